@@ -0,0 +1,307 @@
+use std::{
+	sync::{Arc, LazyLock},
+	time::Duration,
+};
+
+use reqwest::{Proxy, Url};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::Result;
+
+use super::{
+	ApiKey, BlockingTransport, ClientConfig, DEFAULT_USER_AGENT, DriftHook, RateLimit, RateLimiter,
+	ResponseCache, ResponseData, RetryPolicy, SchemaDrift, WithRaw, default_cache,
+	default_rate_limiter, retry::Failure,
+};
+
+/// Synchronous MCSR Ranked API client
+///
+/// Blocking counterpart of [`super::RankedClient`].
+/// Must not be used from within an async runtime.
+/// Requests are sent through a [`BlockingTransport`], which is the [`reqwest::blocking::Client`]
+/// unless another one is set with [`BlockingRankedClientBuilder::transport`].
+#[derive(Debug, Clone)]
+pub struct BlockingRankedClient {
+	http: reqwest::blocking::Client,
+	transport: Arc<dyn BlockingTransport>,
+	config: Arc<ClientConfig>,
+}
+
+impl Default for BlockingRankedClient {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Wraps the `http` client with the default settings of [`BlockingRankedClient::builder`],
+/// including the published rate limit
+impl From<reqwest::blocking::Client> for BlockingRankedClient {
+	fn from(http: reqwest::blocking::Client) -> Self {
+		Self::builder().build_with(http)
+	}
+}
+
+impl BlockingRankedClient {
+	/// Construct a client with the default configuration
+	///
+	/// # Panics
+	/// Panics if the underlying [`reqwest::blocking::Client`] cannot be built,
+	/// use [`BlockingRankedClient::builder`] to handle the error instead
+	pub fn new() -> Self {
+		Self::builder()
+			.build()
+			.expect("Expected a valid default client configuration")
+	}
+
+	/// Create a [`BlockingRankedClientBuilder`] to configure the client
+	pub fn builder() -> BlockingRankedClientBuilder {
+		BlockingRankedClientBuilder::default()
+	}
+
+	/// The underlying [`reqwest::blocking::Client`], used unless a custom transport is set
+	pub fn http_client(&self) -> &reqwest::blocking::Client {
+		&self.http
+	}
+
+	/// The base URL all endpoint paths are resolved against
+	pub fn base_url(&self) -> &str {
+		&self.config.base_url
+	}
+
+	/// Whether the client sends an API key
+	pub fn has_api_key(&self) -> bool {
+		self.config.api_key.is_some()
+	}
+
+	/// The rate limiter requests wait on, if rate limiting is enabled
+	pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+		self.config.rate_limiter.as_ref()
+	}
+	/// The cache responses are served from, if caching is enabled
+	pub fn cache(&self) -> Option<&ResponseCache> {
+		self.config.cache.as_ref()
+	}
+
+	/// Fail without sending anything if an endpoint requiring an API key is used without one
+	#[cfg(feature = "matches")]
+	pub(crate) fn require_api_key(&self) -> Result<()> {
+		self.config.require_api_key()
+	}
+
+	/// Synchronously GET the endpoint at `path` with `params` decoded into `T`, along with its untouched data
+	///
+	/// Any endpoint can be called through it to read fields this crate does not model yet
+	pub fn get_with_raw<T: DeserializeOwned>(
+		&self,
+		path: &str,
+		params: Option<&impl Serialize>,
+	) -> Result<WithRaw<T>> {
+		self.request(path, &[] as &[&str], params)
+	}
+	/// Synchronously GET the untouched data of the endpoint at `path` with `params`, such as `users/doogile`
+	pub fn get_raw(&self, path: &str, params: Option<&impl Serialize>) -> Result<Value> {
+		self.request(path, &[] as &[&str], params)
+	}
+
+	/// The same client sharing the transport and rate limiter, but not the response cache,
+	/// for requests polling for new data
+	#[cfg(feature = "matches")]
+	pub(crate) fn without_cache(&self) -> Self {
+		Self {
+			config: self.config.without_cache(),
+			..self.clone()
+		}
+	}
+
+	pub(crate) fn request<'v, T, V, S>(
+		&self,
+		path: &str,
+		variables: V,
+		params: Option<&impl Serialize>,
+	) -> Result<T>
+	where
+		T: ResponseData,
+		V: IntoIterator<Item = &'v S>,
+		S: AsRef<str> + 'v,
+	{
+		let url = self.config.url(path, variables, params);
+		if let Some(result) = self.config.cached(&url) {
+			return result;
+		}
+		let mut attempt = 0;
+		loop {
+			attempt += 1;
+			let failure = match self.send(&url) {
+				Ok(value) => return Ok(value),
+				Err(failure) => failure,
+			};
+			match self.config.retry.retry_delay(attempt, &failure) {
+				Some(delay) => std::thread::sleep(delay),
+				None => return Err(failure.into_error(attempt)),
+			}
+		}
+	}
+
+	/// Make a single attempt at the request
+	fn send<T: ResponseData>(&self, url: &str) -> Result<T, Failure> {
+		if let Some(rate_limiter) = &self.config.rate_limiter {
+			rate_limiter.acquire_blocking();
+		}
+		let request = self.config.http_request(url).map_err(Failure::fatal)?;
+		let response = self.transport.send(&request)?;
+		let value = self.config.handle_response(url, &response)?;
+		self.config.cache_response(url, response);
+		Ok(value)
+	}
+}
+
+/// Builder for [`BlockingRankedClient`]
+///
+/// Note: this struct supports the builder pattern
+#[derive(Debug)]
+pub struct BlockingRankedClientBuilder {
+	http: reqwest::blocking::ClientBuilder,
+	transport: Option<Arc<dyn BlockingTransport>>,
+	config: ClientConfig,
+	auto_rate_limit: bool,
+}
+
+impl Default for BlockingRankedClientBuilder {
+	fn default() -> Self {
+		Self {
+			http: reqwest::blocking::Client::builder().user_agent(DEFAULT_USER_AGENT),
+			transport: None,
+			config: Default::default(),
+			auto_rate_limit: true,
+		}
+	}
+}
+
+impl BlockingRankedClientBuilder {
+	/// Set the base URL of the API, [`super::DEFAULT_BASE_URL`] is used otherwise
+	///
+	/// Useful for mirrors and local test servers.
+	/// Endpoint paths are appended to the URL, so it may contain a path prefix
+	pub fn base_url(mut self, base_url: Url) -> Self {
+		self.config.set_base_url(&base_url);
+		self
+	}
+	/// Set the private API key sent in the `API-Key` header of every request
+	pub fn api_key(mut self, api_key: impl Into<ApiKey>) -> Self {
+		self.config.api_key = Some(api_key.into());
+		self
+	}
+	/// Read the API key from the [`super::API_KEY_ENV_VAR`] environment variable
+	///
+	/// Leaves the current key untouched if the variable is not set
+	pub fn api_key_from_env(mut self) -> Self {
+		if let Some(api_key) = ApiKey::from_env() {
+			self.config.api_key = Some(api_key);
+		}
+		self
+	}
+	/// Limit the requests of this client to `limit`
+	///
+	/// By default the published limit is used, see [`RateLimit::published`]
+	pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+		self.config.rate_limiter = Some(RateLimiter::new(limit));
+		self
+	}
+	/// Use a shared `rate_limiter`, so that several clients respect one common limit
+	pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+		self.config.rate_limiter = Some(rate_limiter);
+		self
+	}
+	/// Set the policy for retrying failed requests
+	///
+	/// By default [`RetryPolicy::default`] is used
+	pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+		self.config.retry = retry;
+		self
+	}
+	/// Disable retrying failed requests
+	pub fn no_retry(mut self) -> Self {
+		self.config.retry = RetryPolicy::none();
+		self
+	}
+	/// Disable client-side rate limiting
+	pub fn no_rate_limit(mut self) -> Self {
+		self.config.rate_limiter = None;
+		self.auto_rate_limit = false;
+		self
+	}
+	/// Set the total timeout of each request
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.http = self.http.timeout(timeout);
+		self
+	}
+	/// Set the timeout for connecting to the API
+	pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+		self.http = self.http.connect_timeout(timeout);
+		self
+	}
+	/// Set the `User-Agent` header, [`DEFAULT_USER_AGENT`] is used otherwise
+	pub fn user_agent(mut self, user_agent: &str) -> Self {
+		self.http = self.http.user_agent(user_agent);
+		self
+	}
+	/// Add a proxy to route the requests through
+	pub fn proxy(mut self, proxy: Proxy) -> Self {
+		self.http = self.http.proxy(proxy);
+		self
+	}
+	/// Serve repeated requests from the shared response `cache` until their TTL expires
+	///
+	/// Responses are not cached by default
+	pub fn cache(mut self, cache: ResponseCache) -> Self {
+		self.config.cache = Some(cache);
+		self
+	}
+	/// Call `hook` with the fields of every successful response which the target type ignored
+	///
+	/// Meant for noticing API changes, the responses are still decoded as usual
+	pub fn on_schema_drift(mut self, hook: impl Fn(&SchemaDrift) + Send + Sync + 'static) -> Self {
+		self.config.drift_hook = Some(DriftHook::new(hook));
+		self
+	}
+	/// Send the requests through a custom `transport` instead of the [`reqwest::blocking::Client`]
+	///
+	/// The timeout, user agent and proxy settings only apply to the [`reqwest::blocking::Client`]
+	pub fn transport(mut self, transport: impl BlockingTransport + 'static) -> Self {
+		self.transport = Some(Arc::new(transport));
+		self
+	}
+	/// Build the [`BlockingRankedClient`]
+	pub fn build(mut self) -> Result<BlockingRankedClient> {
+		let http = std::mem::take(&mut self.http).build()?;
+		Ok(self.build_with(http))
+	}
+	/// Build the [`BlockingRankedClient`] around an already built `http` client
+	fn build_with(mut self, http: reqwest::blocking::Client) -> BlockingRankedClient {
+		if self.auto_rate_limit {
+			self.config.ensure_rate_limiter();
+		}
+		BlockingRankedClient {
+			transport: self.transport.unwrap_or_else(|| Arc::new(http.clone())),
+			http,
+			config: Arc::new(self.config),
+		}
+	}
+}
+
+/// Shared client used by the static `*_blocking` request functions
+///
+/// Uses the API key from [`super::API_KEY_ENV_VAR`] if it is set
+pub(crate) fn default_blocking_client() -> &'static BlockingRankedClient {
+	static CLIENT: LazyLock<BlockingRankedClient> = LazyLock::new(|| {
+		let mut builder = BlockingRankedClient::builder()
+			.api_key_from_env()
+			.rate_limiter(default_rate_limiter().clone());
+		builder.config.cache = default_cache();
+		builder
+			.build()
+			.expect("Expected a valid default client configuration")
+	});
+	&CLIENT
+}
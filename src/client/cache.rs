@@ -0,0 +1,365 @@
+use std::{
+	hash::{BuildHasher, RandomState},
+	num::NonZeroUsize,
+	sync::{
+		Arc, LazyLock, Mutex, MutexGuard, OnceLock,
+		atomic::{AtomicU64, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use serde::Serialize;
+
+use super::{ApiKey, HttpResponse};
+use crate::helpers::construct_url;
+
+/// Group of endpoints sharing the TTL of their cached responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CachedEndpoint {
+	/// A single match, `matches/{id}`
+	Match,
+	/// Lists of matches, such as `matches` and `users/{}/matches`
+	Matches,
+	/// Users, `users/{}` and `users/{}/seasons`
+	User,
+	/// Versus stats of two users, `users/{}/versus/{}`
+	Versus,
+	/// The elo, phase and record leaderboards
+	Leaderboard,
+	/// Weekly races, `weekly-race` and `weekly-race/{}`
+	WeeklyRace,
+	/// Live matches, `live`
+	Live,
+	/// Any other endpoint
+	Other,
+}
+
+impl CachedEndpoint {
+	/// The endpoint group of the request `path`, without the base URL
+	pub fn of(path: &str) -> Self {
+		let path = path.split(['?', '#']).next().unwrap_or_default();
+		let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+		match segments.as_slice() {
+			["matches", _] => Self::Match,
+			["matches"] | ["users", _, "matches"] | ["users", _, "versus", _, "matches"] => {
+				Self::Matches
+			}
+			["users", _, "versus", _] => Self::Versus,
+			["users", _] | ["users", _, "seasons"] => Self::User,
+			["leaderboard" | "phase-leaderboard" | "record-leaderboard"] => Self::Leaderboard,
+			["weekly-race"] | ["weekly-race", _] => Self::WeeklyRace,
+			["live"] => Self::Live,
+			_ => Self::Other,
+		}
+	}
+}
+
+/// Capacity and TTLs of a [`ResponseCache`]
+///
+/// By default finished matches are kept for a day, leaderboards and weekly races
+/// for 5 minutes, users and versus stats for a minute and lists of matches for 30 seconds.
+/// Live matches and unknown endpoints are not cached.
+/// The lists of matches are always requested anew by the match watcher and the match sync,
+/// as a cached page would hide the newly finished matches.
+///
+/// Note: this struct supports the builder pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+	/// Maximum amount of cached responses, the least recently used ones are evicted first
+	pub capacity: NonZeroUsize,
+	pub match_ttl: Duration,
+	pub matches_ttl: Duration,
+	pub user_ttl: Duration,
+	pub versus_ttl: Duration,
+	pub leaderboard_ttl: Duration,
+	pub weekly_race_ttl: Duration,
+	pub live_ttl: Duration,
+	pub other_ttl: Duration,
+}
+
+impl Default for CachePolicy {
+	fn default() -> Self {
+		Self {
+			capacity: NonZeroUsize::new(256).expect("Expected a non-zero capacity"),
+			match_ttl: Duration::from_secs(24 * 60 * 60),
+			matches_ttl: Duration::from_secs(30),
+			user_ttl: Duration::from_secs(60),
+			versus_ttl: Duration::from_secs(60),
+			leaderboard_ttl: Duration::from_secs(5 * 60),
+			weekly_race_ttl: Duration::from_secs(5 * 60),
+			live_ttl: Duration::ZERO,
+			other_ttl: Duration::ZERO,
+		}
+	}
+}
+
+impl CachePolicy {
+	/// Set the `capacity` field
+	pub fn capacity(mut self, capacity: NonZeroUsize) -> Self {
+		self.capacity = capacity;
+		self
+	}
+	/// Set the TTL of the `endpoint`, [`Duration::ZERO`] disables caching it
+	pub fn ttl(mut self, endpoint: CachedEndpoint, ttl: Duration) -> Self {
+		*self.ttl_mut(endpoint) = ttl;
+		self
+	}
+
+	/// The TTL of the `endpoint`
+	pub fn ttl_of(&self, endpoint: CachedEndpoint) -> Duration {
+		match endpoint {
+			CachedEndpoint::Match => self.match_ttl,
+			CachedEndpoint::Matches => self.matches_ttl,
+			CachedEndpoint::User => self.user_ttl,
+			CachedEndpoint::Versus => self.versus_ttl,
+			CachedEndpoint::Leaderboard => self.leaderboard_ttl,
+			CachedEndpoint::WeeklyRace => self.weekly_race_ttl,
+			CachedEndpoint::Live => self.live_ttl,
+			CachedEndpoint::Other => self.other_ttl,
+		}
+	}
+	fn ttl_mut(&mut self, endpoint: CachedEndpoint) -> &mut Duration {
+		match endpoint {
+			CachedEndpoint::Match => &mut self.match_ttl,
+			CachedEndpoint::Matches => &mut self.matches_ttl,
+			CachedEndpoint::User => &mut self.user_ttl,
+			CachedEndpoint::Versus => &mut self.versus_ttl,
+			CachedEndpoint::Leaderboard => &mut self.leaderboard_ttl,
+			CachedEndpoint::WeeklyRace => &mut self.weekly_race_ttl,
+			CachedEndpoint::Live => &mut self.live_ttl,
+			CachedEndpoint::Other => &mut self.other_ttl,
+		}
+	}
+}
+
+/// Hit and miss counters of a [`ResponseCache`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+	pub hits: u64,
+	pub misses: u64,
+}
+
+impl CacheStats {
+	/// Fraction of lookups which were hits, `None` before the first lookup
+	pub fn hit_rate(&self) -> Option<f64> {
+		let total = self.hits + self.misses;
+		(total > 0).then(|| self.hits as f64 / total as f64)
+	}
+}
+
+/// Hasher of the API keys in the cache keys, so that the keys themselves are not kept
+static API_KEY_HASHER: LazyLock<RandomState> = LazyLock::new(RandomState::new);
+
+/// Key of a cached response: the URL of the request and a hash of its API key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+	url: Box<str>,
+	/// Start of the endpoint path in `url`
+	path_start: usize,
+	api_key: Option<u64>,
+}
+
+impl CacheKey {
+	/// Key of the request to `url` with the `api_key`, the endpoint path follows `base_url`
+	pub(crate) fn new(url: &str, base_url: &str, api_key: Option<&ApiKey>) -> Self {
+		let path_start = url
+			.strip_prefix(base_url)
+			.map_or(0, |path| url.len() - path.trim_start_matches('/').len());
+		Self {
+			url: url.into(),
+			path_start,
+			api_key: api_key.map(|key| API_KEY_HASHER.hash_one(key.as_str())),
+		}
+	}
+
+	/// The endpoint path and query, such as `users/doogile?season=7`
+	fn path(&self) -> &str {
+		&self.url[self.path_start..]
+	}
+	fn endpoint(&self) -> CachedEndpoint {
+		CachedEndpoint::of(self.path())
+	}
+}
+
+#[derive(Debug)]
+struct Entry {
+	expires: Instant,
+	response: HttpResponse,
+}
+
+#[derive(Debug)]
+struct CacheState {
+	policy: CachePolicy,
+	entries: LruCache<CacheKey, Entry>,
+}
+
+impl CacheState {
+	/// Remove the entries whose key matches `predicate`, returns whether there were any
+	fn remove_where(&mut self, predicate: impl Fn(&CacheKey) -> bool) -> bool {
+		let keys: Vec<CacheKey> = self
+			.entries
+			.iter()
+			.map(|(key, _)| key)
+			.filter(|key| predicate(key))
+			.cloned()
+			.collect();
+		for key in &keys {
+			self.entries.pop(key);
+		}
+		!keys.is_empty()
+	}
+}
+
+/// In-memory LRU cache of successful responses, keyed by their URL and API key
+///
+/// Responses are only served to clients using the same API key as the client which requested them.
+/// Cached responses are served without waiting on the rate limiter,
+/// and are not reported to the schema drift hook again.
+/// Clones share the same entries and counters, so a single cache can be passed to several clients.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use mcsr_ranked_api::client::{CachePolicy, CachedEndpoint, RankedClient, ResponseCache};
+///
+/// let cache = ResponseCache::new(
+///     CachePolicy::default().ttl(CachedEndpoint::Leaderboard, Duration::from_secs(60)),
+/// );
+/// let client = RankedClient::builder().cache(cache.clone()).build();
+/// assert_eq!(cache.stats().hits, 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+	state: Arc<Mutex<CacheState>>,
+	hits: Arc<AtomicU64>,
+	misses: Arc<AtomicU64>,
+}
+
+impl Default for ResponseCache {
+	fn default() -> Self {
+		Self::new(CachePolicy::default())
+	}
+}
+
+impl ResponseCache {
+	/// Construct an empty cache following the `policy`
+	pub fn new(policy: CachePolicy) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(CacheState {
+				entries: LruCache::new(policy.capacity),
+				policy,
+			})),
+			hits: Default::default(),
+			misses: Default::default(),
+		}
+	}
+
+	fn state(&self) -> MutexGuard<'_, CacheState> {
+		self.state.lock().unwrap_or_else(|err| err.into_inner())
+	}
+
+	/// The policy of the cache
+	pub fn policy(&self) -> CachePolicy {
+		self.state().policy.clone()
+	}
+	/// The hit and miss counters
+	pub fn stats(&self) -> CacheStats {
+		CacheStats {
+			hits: self.hits.load(Ordering::Relaxed),
+			misses: self.misses.load(Ordering::Relaxed),
+		}
+	}
+	/// Reset the hit and miss counters
+	pub fn reset_stats(&self) {
+		self.hits.store(0, Ordering::Relaxed);
+		self.misses.store(0, Ordering::Relaxed);
+	}
+	/// Amount of cached responses, including expired ones not evicted yet
+	pub fn len(&self) -> usize {
+		self.state().entries.len()
+	}
+	/// Whether no response is cached
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Remove the cached responses of the endpoint at `path` with `params`, such as `users/doogile`,
+	/// for every API key and base URL, returns whether there were any
+	///
+	/// The `path` and `params` are those of [`super::RankedClient::get_raw`]
+	pub fn invalidate(&self, path: &str, params: Option<&impl Serialize>) -> bool {
+		let path = construct_url(path.trim_start_matches('/'), &[] as &[&str], params);
+		self.state().remove_where(|key| key.path() == &*path)
+	}
+	/// Remove the cached responses of the `endpoint`
+	pub fn invalidate_endpoint(&self, endpoint: CachedEndpoint) {
+		self.state().remove_where(|key| key.endpoint() == endpoint);
+	}
+	/// Remove every cached response
+	pub fn clear(&self) {
+		self.state().entries.clear();
+	}
+
+	/// The fresh cached response to the request of `key` at `now`, counting the lookup
+	///
+	/// Endpoints which are not cached are neither looked up nor counted
+	pub(super) fn get_at(&self, key: &CacheKey, now: Instant) -> Option<HttpResponse> {
+		let mut state = self.state();
+		if state.policy.ttl_of(key.endpoint()).is_zero() {
+			return None;
+		}
+		let response = match state.entries.get(key) {
+			Some(entry) if entry.expires > now => Some(entry.response.clone()),
+			Some(_) => {
+				state.entries.pop(key);
+				None
+			}
+			None => None,
+		};
+		let counter = if response.is_some() {
+			&self.hits
+		} else {
+			&self.misses
+		};
+		counter.fetch_add(1, Ordering::Relaxed);
+		response
+	}
+	/// Cache the `response` to the request of `key` received at `now`
+	pub(super) fn insert_at(&self, key: CacheKey, response: HttpResponse, now: Instant) {
+		let mut state = self.state();
+		let ttl = state.policy.ttl_of(key.endpoint());
+		if ttl.is_zero() {
+			return;
+		}
+		let entry = Entry {
+			expires: now + ttl,
+			response,
+		};
+		state.entries.put(key, entry);
+	}
+	pub(crate) fn get(&self, key: &CacheKey) -> Option<HttpResponse> {
+		self.get_at(key, Instant::now())
+	}
+	pub(crate) fn insert(&self, key: CacheKey, response: HttpResponse) {
+		self.insert_at(key, response, Instant::now())
+	}
+}
+
+/// Cache of the default clients, fixed once the first of them is built
+static DEFAULT_CACHE: OnceLock<Option<ResponseCache>> = OnceLock::new();
+
+/// Cache the responses of the shared clients used by the static request functions,
+/// such as [`crate::user::identifier::UserIdentifier::get_user`]
+///
+/// Must be called before the first static request, fails returning the `cache` otherwise
+pub fn set_default_cache(cache: ResponseCache) -> Result<(), ResponseCache> {
+	DEFAULT_CACHE
+		.set(Some(cache))
+		.map_err(|cache| cache.expect("Expected the cache to be set"))
+}
+
+/// The cache of the default clients, no more cache can be set afterwards
+pub(crate) fn default_cache() -> Option<ResponseCache> {
+	DEFAULT_CACHE.get_or_init(|| None).clone()
+}
@@ -0,0 +1,214 @@
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+};
+
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "blocking")]
+use super::BlockingTransport;
+use super::{HttpRequest, HttpResponse, Transport, TransportFuture};
+use crate::{Error, Result};
+
+/// Environment variable the mode is read from by [`CassetteMode::from_env`]
+pub const CASSETTE_ENV_VAR: &str = "MCSR_RANKED_CASSETTE";
+
+/// Whether a [`Cassette`] records new fixtures or replays existing ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CassetteMode {
+	/// Send every request and store its response as a fixture
+	Record,
+	/// Serve the stored fixtures without sending any request
+	#[default]
+	Replay,
+}
+
+impl CassetteMode {
+	/// [`CassetteMode::Record`] if the [`CASSETTE_ENV_VAR`] environment variable
+	/// is set to `record`, [`CassetteMode::Replay`] otherwise
+	pub fn from_env() -> Self {
+		match std::env::var(CASSETTE_ENV_VAR) {
+			Ok(mode) if mode.trim().eq_ignore_ascii_case("record") => Self::Record,
+			_ => Self::Replay,
+		}
+	}
+}
+
+/// Stored response to a request
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+	url: Box<str>,
+	status: u16,
+	/// The body, if it is valid JSON
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	body: Option<Value>,
+	/// The body, if it is not valid JSON
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	text: Option<String>,
+}
+
+/// Transport recording responses to fixture files and replaying them
+///
+/// Every request is stored in its own JSON file in the fixture directory,
+/// named after the path and query of the request URL. In replay mode the
+/// `inner` transport is never used and a missing fixture is reported as
+/// [`Error::Transport`], so tests using a cassette run without any network.
+///
+/// # Examples
+/// ```no_run
+/// # async fn run() -> mcsr_ranked_api::Result<()> {
+/// use mcsr_ranked_api::{
+///     client::{Cassette, RankedClient},
+///     user::identifier::UserIdentifier,
+/// };
+///
+/// // Records with `MCSR_RANKED_CASSETTE=record`, replays otherwise
+/// let cassette = Cassette::from_env("fixtures", reqwest::Client::new());
+/// let client = RankedClient::builder().transport(cassette).build()?;
+/// let user = client.get_user(&UserIdentifier::Nickname("RED_LIME"), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette<T> {
+	dir: PathBuf,
+	mode: CassetteMode,
+	inner: T,
+}
+
+impl<T> Cassette<T> {
+	/// Construct a cassette storing the fixtures in `dir`,
+	/// sending the requests through `inner` when recording
+	pub fn new(dir: impl Into<PathBuf>, mode: CassetteMode, inner: T) -> Self {
+		Self {
+			dir: dir.into(),
+			mode,
+			inner,
+		}
+	}
+	/// Construct a cassette with the mode read from the environment,
+	/// see [`CassetteMode::from_env`]
+	pub fn from_env(dir: impl Into<PathBuf>, inner: T) -> Self {
+		Self::new(dir, CassetteMode::from_env(), inner)
+	}
+
+	/// The directory the fixtures are stored in
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+	/// Whether the cassette records or replays
+	pub fn mode(&self) -> CassetteMode {
+		self.mode
+	}
+
+	/// Path of the fixture for the request to `url`
+	pub fn fixture_path(&self, url: &str) -> PathBuf {
+		self.dir.join(fixture_name(url))
+	}
+
+	fn replay(&self, url: &str) -> Result<HttpResponse> {
+		let path = self.fixture_path(url);
+		let fixture: Fixture = fs::read(&path)
+			.and_then(|data| serde_json::from_slice(&data).map_err(io::Error::from))
+			.map_err(|err| {
+				transport_error(format!("fixture {} for {url}: {err}", path.display()))
+			})?;
+		if fixture_name(&fixture.url) != fixture_name(url) {
+			return Err(transport_error(format!(
+				"fixture {} was recorded for {}, not {url}",
+				path.display(),
+				fixture.url
+			)));
+		}
+
+		let status =
+			StatusCode::from_u16(fixture.status).map_err(|err| transport_error(err.to_string()))?;
+		let body = match (fixture.body, fixture.text) {
+			(Some(body), _) => body.to_string(),
+			(None, text) => text.unwrap_or_default(),
+		};
+		Ok(HttpResponse::new(status, body))
+	}
+
+	fn record(&self, url: &str, response: &HttpResponse) -> Result<()> {
+		let (body, text) = match serde_json::from_slice(&response.body) {
+			Ok(body) => (Some(body), None),
+			Err(_) => (
+				None,
+				Some(String::from_utf8_lossy(&response.body).into_owned()),
+			),
+		};
+		let fixture = Fixture {
+			url: url.into(),
+			status: response.status.as_u16(),
+			body,
+			text,
+		};
+		let mut data =
+			serde_json::to_vec_pretty(&fixture).map_err(|err| transport_error(err.to_string()))?;
+		data.push(b'\n');
+		fs::create_dir_all(&self.dir)
+			.and_then(|()| fs::write(self.fixture_path(url), data))
+			.map_err(|err| transport_error(format!("recording {url}: {err}")))
+	}
+}
+
+impl<T: Transport> Transport for Cassette<T> {
+	fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+		Box::pin(async move {
+			match self.mode {
+				CassetteMode::Replay => self.replay(&request.url),
+				CassetteMode::Record => {
+					let response = self.inner.send(request).await?;
+					self.record(&request.url, &response)?;
+					Ok(response)
+				}
+			}
+		})
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl<T: BlockingTransport> BlockingTransport for Cassette<T> {
+	fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+		match self.mode {
+			CassetteMode::Replay => self.replay(&request.url),
+			CassetteMode::Record => {
+				let response = self.inner.send(request)?;
+				self.record(&request.url, &response)?;
+				Ok(response)
+			}
+		}
+	}
+}
+
+fn transport_error(message: String) -> Error {
+	Error::Transport(message.into())
+}
+
+/// File name of the fixture for `url`, made of its path and query
+fn fixture_name(url: &str) -> String {
+	let (path, query) = match Url::parse(url) {
+		Ok(url) => (url.path().to_owned(), url.query().map(str::to_owned)),
+		Err(_) => (url.to_owned(), None),
+	};
+	let mut name = match path.trim_matches('/') {
+		"" => "index".to_owned(),
+		path => path.replace('/', "_"),
+	};
+	if let Some(query) = query {
+		name.push('-');
+		name.push_str(&query.replace('&', "-"));
+	}
+	let mut name: String = name
+		.chars()
+		.map(|c| match c {
+			'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' | '=' => c,
+			_ => '_',
+		})
+		.collect();
+	name.push_str(".json");
+	name
+}
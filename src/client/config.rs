@@ -0,0 +1,234 @@
+use std::{
+	fmt::{self, Debug},
+	sync::Arc,
+};
+
+use reqwest::{
+	StatusCode, Url,
+	header::{HeaderMap, HeaderValue},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+	ApiError, Error, Result,
+	helpers::{construct_url, decode, join_url},
+	types::DeResult,
+};
+
+use super::{
+	DEFAULT_BASE_URL, DriftHook, HttpRequest, HttpResponse, RateLimit, RateLimiter, ResponseCache,
+	ResponseData, RetryPolicy,
+	cache::CacheKey,
+	retry::{Failure, is_transient, retry_after},
+};
+
+/// Environment variable the API key is read from by [`ApiKey::from_env`]
+pub const API_KEY_ENV_VAR: &str = "MCSR_RANKED_API_KEY";
+/// Header the API key is sent in
+const API_KEY_HEADER: &str = "API-Key";
+
+/// Private MCSR Ranked API key
+///
+/// Grants higher rate limits and access to restricted endpoints.
+/// The key is redacted from the `Debug` output
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(Box<str>);
+
+impl ApiKey {
+	/// Construct an API key from its string value
+	pub fn new(key: impl Into<Box<str>>) -> Self {
+		Self(key.into())
+	}
+	/// Read the API key from the [`API_KEY_ENV_VAR`] environment variable
+	///
+	/// Returns `None` if the variable is not set or is empty
+	pub fn from_env() -> Option<Self> {
+		std::env::var(API_KEY_ENV_VAR)
+			.ok()
+			.filter(|key| !key.trim().is_empty())
+			.map(|key| Self::new(key.trim()))
+	}
+	/// The key itself
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+impl Debug for ApiKey {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("ApiKey(<redacted>)")
+	}
+}
+impl From<&str> for ApiKey {
+	fn from(value: &str) -> Self {
+		Self::new(value)
+	}
+}
+impl From<String> for ApiKey {
+	fn from(value: String) -> Self {
+		Self::new(value)
+	}
+}
+
+/// Settings shared by the async and blocking clients
+#[derive(Debug, Clone)]
+pub(crate) struct ClientConfig {
+	/// Base URL without the trailing slash
+	pub(crate) base_url: Arc<str>,
+	pub(crate) api_key: Option<ApiKey>,
+	pub(crate) rate_limiter: Option<RateLimiter>,
+	pub(crate) retry: RetryPolicy,
+	pub(crate) drift_hook: Option<DriftHook>,
+	pub(crate) cache: Option<ResponseCache>,
+}
+
+impl Default for ClientConfig {
+	fn default() -> Self {
+		Self {
+			base_url: DEFAULT_BASE_URL.into(),
+			api_key: None,
+			rate_limiter: None,
+			retry: Default::default(),
+			drift_hook: None,
+			cache: None,
+		}
+	}
+}
+
+impl ClientConfig {
+	pub(crate) fn set_base_url(&mut self, base_url: &Url) {
+		self.base_url = base_url.as_str().trim_end_matches('/').into();
+	}
+
+	/// Use the published rate limit matching the API key configuration
+	/// if no limiter was set explicitly
+	pub(crate) fn ensure_rate_limiter(&mut self) {
+		if self.rate_limiter.is_none() {
+			self.rate_limiter = Some(RateLimiter::new(RateLimit::published(
+				self.api_key.is_some(),
+			)));
+		}
+	}
+
+	/// Resolve the endpoint `path` against the base URL
+	pub(crate) fn url<'v, V, S>(
+		&self,
+		path: &str,
+		variables: V,
+		params: Option<&impl Serialize>,
+	) -> Box<str>
+	where
+		V: IntoIterator<Item = &'v S>,
+		S: AsRef<str> + 'v,
+	{
+		construct_url(join_url(&self.base_url, path), variables, params)
+	}
+
+	/// Build the request to `url` with the configured headers
+	pub(crate) fn http_request(&self, url: &str) -> Result<HttpRequest> {
+		let mut headers = HeaderMap::new();
+		if let Some(api_key) = &self.api_key {
+			let mut value =
+				HeaderValue::from_str(api_key.as_str()).map_err(|_| Error::InvalidApiKey)?;
+			value.set_sensitive(true);
+			headers.insert(API_KEY_HEADER, value);
+		}
+		Ok(HttpRequest {
+			url: url.into(),
+			headers,
+		})
+	}
+
+	/// The same settings without the response cache
+	#[cfg(feature = "matches")]
+	pub(crate) fn without_cache(self: &Arc<Self>) -> Arc<Self> {
+		match self.cache {
+			Some(_) => Arc::new(Self {
+				cache: None,
+				..(**self).clone()
+			}),
+			None => self.clone(),
+		}
+	}
+
+	/// The cache key of the request to `url`
+	fn cache_key(&self, url: &str) -> CacheKey {
+		CacheKey::new(url, &self.base_url, self.api_key.as_ref())
+	}
+	/// Decode the fresh cached response to `url`, if there is one
+	///
+	/// The response was reported to the drift hook when it was received, so it is not reported again
+	pub(crate) fn cached<T: ResponseData>(&self, url: &str) -> Option<Result<T>> {
+		let response = self.cache.as_ref()?.get(&self.cache_key(url))?;
+		Some(
+			self.decode_response(url, &response, false)
+				.map_err(|failure| failure.into_error(1)),
+		)
+	}
+	/// Cache the successful `response` to `url`
+	pub(crate) fn cache_response(&self, url: &str, response: HttpResponse) {
+		if let Some(cache) = &self.cache {
+			cache.insert(self.cache_key(url), response);
+		}
+	}
+
+	/// Decode the `response` to the request to `url`
+	pub(crate) fn handle_response<T: ResponseData>(
+		&self,
+		url: &str,
+		response: &HttpResponse,
+	) -> Result<T, Failure> {
+		self.decode_response(url, response, true)
+	}
+
+	fn decode_response<T: ResponseData>(
+		&self,
+		url: &str,
+		response: &HttpResponse,
+		report_drift: bool,
+	) -> Result<T, Failure> {
+		let status = response.status;
+		self.check_status(status).map_err(Failure::fatal)?;
+
+		let result = match decode::<T>(url, status, &response.body) {
+			Ok(result) => {
+				if report_drift
+					&& let DeResult::Success(value) = &result
+					&& let Some(hook) = &self.drift_hook
+				{
+					hook.report(url, &response.body, value);
+				}
+				result.into_result(Some(status))
+			}
+			// A body which is not JSON is best described by its status
+			Err(_) if !status.is_success() => {
+				Err(Error::Api(ApiError::new(Some(status), Value::Null)))
+			}
+			Err(err) => Err(err),
+		};
+		result.map_err(|error| Failure {
+			error,
+			retryable: is_transient(status),
+			retry_after: retry_after(&response.headers),
+		})
+	}
+
+	/// Fail with [`Error::ApiKeyRequired`] unless an API key is configured
+	#[cfg(feature = "matches")]
+	pub(crate) fn require_api_key(&self) -> Result<()> {
+		match self.api_key {
+			Some(_) => Ok(()),
+			None => Err(Error::ApiKeyRequired),
+		}
+	}
+
+	/// Check the response `status` for errors which are not reported in the body
+	pub(crate) fn check_status(&self, status: StatusCode) -> Result<()> {
+		match status {
+			StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN if self.api_key.is_some() => {
+				Err(Error::InvalidApiKey)
+			}
+			_ => Ok(()),
+		}
+	}
+}
@@ -0,0 +1,146 @@
+use std::{
+	fmt::{self, Debug, Display},
+	sync::Arc,
+};
+
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use serde_ignored::Path;
+use serde_json::Value;
+
+/// Types responses are decoded into
+///
+/// With the `serialize` feature they are serialized again to also find the ignored fields
+/// next to a `#[serde(flatten)]` field, which are invisible to [`serde_ignored`]
+#[cfg(feature = "serialize")]
+pub(crate) trait ResponseData: DeserializeOwned + Serialize {}
+#[cfg(feature = "serialize")]
+impl<T: DeserializeOwned + Serialize> ResponseData for T {}
+#[cfg(not(feature = "serialize"))]
+pub(crate) trait ResponseData: DeserializeOwned {}
+#[cfg(not(feature = "serialize"))]
+impl<T: DeserializeOwned> ResponseData for T {}
+
+/// Fields of a response which were present but not consumed by the target type
+///
+/// Reported to the hook set with [`super::RankedClientBuilder::on_schema_drift`].
+/// Fields ignored by a struct with a `#[serde(flatten)]` field, such as
+/// [`crate::user::info::UserInfo`], are only found with the `serialize` feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDrift {
+	/// URL of the request
+	pub url: Box<str>,
+	/// Name of the type the response was decoded into
+	pub type_name: &'static str,
+	/// JSON paths of the ignored fields, such as `data.players[0].newField`
+	pub paths: Box<[Box<str>]>,
+}
+
+impl Display for SchemaDrift {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}: fields ignored by {}: {}",
+			self.url,
+			self.type_name,
+			self.paths.join(", ")
+		)
+	}
+}
+
+/// Callback receiving the [`SchemaDrift`] of responses
+#[derive(Clone)]
+pub(crate) struct DriftHook(Arc<dyn Fn(&SchemaDrift) + Send + Sync>);
+
+impl DriftHook {
+	pub(crate) fn new(hook: impl Fn(&SchemaDrift) + Send + Sync + 'static) -> Self {
+		Self(Arc::new(hook))
+	}
+
+	/// Report the fields of the response `body` to `url` which `T` ignores, if there are any
+	///
+	/// The data is decoded a second time, so the order of the response keys does not matter.
+	/// `value` is the data decoded from the `body`
+	pub(crate) fn report<T: ResponseData>(&self, url: &str, body: &[u8], value: &T) {
+		let paths = ignored_paths(body, value);
+		if paths.is_empty() {
+			return;
+		}
+		(self.0)(&SchemaDrift {
+			url: url.into(),
+			type_name: std::any::type_name::<T>(),
+			paths: paths.into(),
+		});
+	}
+}
+impl Debug for DriftHook {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("DriftHook")
+	}
+}
+
+/// Paths of the fields in the data of the response `body` which `T` does not consume
+///
+/// `value` is the data decoded from the `body`, it is unused without the `serialize` feature
+#[cfg_attr(not(feature = "serialize"), allow(unused_variables))]
+fn ignored_paths<T: ResponseData>(body: &[u8], value: &T) -> Vec<Box<str>> {
+	let mut paths = Vec::new();
+	let Ok(Value::Object(mut response)) = serde_json::from_slice(body) else {
+		return paths;
+	};
+	let Some(data) = response.remove("data") else {
+		return paths;
+	};
+	#[cfg(feature = "serialize")]
+	if let Ok(serialized) = serde_json::to_value(value) {
+		missing_paths(&data, &serialized, "data", &mut paths);
+	}
+	let _ = serde_ignored::deserialize::<_, _, T>(data, |path| {
+		paths.push(format!("data{}", path_suffix(&path)).into())
+	});
+	paths.sort();
+	paths.dedup();
+	paths
+}
+
+/// Push the paths of the non-null fields of `data` which are missing from the `serialized` value
+///
+/// Objects with keys which are only in the serialized value are skipped, as their renamed keys,
+/// such as aliases or reformatted UUIDs, cannot be told apart from the ignored ones
+#[cfg(feature = "serialize")]
+fn missing_paths(data: &Value, serialized: &Value, path: &str, paths: &mut Vec<Box<str>>) {
+	match (data, serialized) {
+		(Value::Object(data), Value::Object(serialized)) => {
+			let renamed = serialized
+				.iter()
+				.any(|(key, value)| !value.is_null() && !data.contains_key(key));
+			for (key, value) in data {
+				let path = format!("{path}.{key}");
+				match serialized.get(key) {
+					Some(serialized) => missing_paths(value, serialized, &path, paths),
+					None if !renamed && !value.is_null() => paths.push(path.into()),
+					None => {}
+				}
+			}
+		}
+		(Value::Array(data), Value::Array(serialized)) => {
+			for (index, (value, serialized)) in data.iter().zip(serialized).enumerate() {
+				missing_paths(value, serialized, &format!("{path}[{index}]"), paths);
+			}
+		}
+		_ => {}
+	}
+}
+
+/// Format the `path` like the paths of [`crate::DecodeError`], relative to its root
+fn path_suffix(path: &Path) -> String {
+	match path {
+		Path::Root => String::new(),
+		Path::Seq { parent, index } => format!("{}[{index}]", path_suffix(parent)),
+		Path::Map { parent, key } => format!("{}.{key}", path_suffix(parent)),
+		Path::Some { parent }
+		| Path::NewtypeStruct { parent }
+		| Path::NewtypeVariant { parent } => path_suffix(parent),
+	}
+}
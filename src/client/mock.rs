@@ -0,0 +1,127 @@
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex, MutexGuard},
+};
+
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::json;
+
+#[cfg(feature = "blocking")]
+use super::BlockingTransport;
+use super::{HttpRequest, HttpResponse, Transport, TransportFuture};
+#[cfg(feature = "blocking")]
+use crate::Result;
+
+#[derive(Debug, Default)]
+struct MockState {
+	responses: HashMap<Box<str>, HttpResponse>,
+	requests: Vec<HttpRequest>,
+}
+
+/// In-memory transport serving canned responses by URL
+///
+/// Responses are matched against the full request URL, including the query.
+/// Requests to unknown URLs get a `404` response with an API error.
+/// Clones share the responses and the request log, so a clone can be
+/// given to the client while the original is used to inspect the requests.
+///
+/// Note: this struct supports the builder pattern
+///
+/// # Examples
+/// ```
+/// # async fn run() -> mcsr_ranked_api::Result<()> {
+/// use mcsr_ranked_api::client::{MockTransport, RankedClient};
+///
+/// let mock = MockTransport::new().with_data(
+///     "https://api.mcsrranked.com/weekly-race",
+///     serde_json::json!({ "id": 1 }),
+/// );
+/// let client = RankedClient::builder().transport(mock.clone()).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+	state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+	/// Construct a transport without any responses
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn state(&self) -> MutexGuard<'_, MockState> {
+		self.state.lock().unwrap_or_else(|err| err.into_inner())
+	}
+
+	/// Serve `response` for requests to `url`, replacing the previous response
+	pub fn insert(&self, url: impl Into<Box<str>>, response: HttpResponse) {
+		self.state().responses.insert(url.into(), response);
+	}
+	/// Serve `response` for requests to `url`
+	pub fn with_response(self, url: impl Into<Box<str>>, response: HttpResponse) -> Self {
+		self.insert(url, response);
+		self
+	}
+	/// Serve the raw JSON `body` with status `200` for requests to `url`
+	pub fn with_body(self, url: impl Into<Box<str>>, body: impl Into<Vec<u8>>) -> Self {
+		self.with_response(url, HttpResponse::new(StatusCode::OK, body))
+	}
+	/// Serve a successful response containing `data` for requests to `url`
+	pub fn with_data(self, url: impl Into<Box<str>>, data: impl Serialize) -> Self {
+		let body = json!({ "status": "success", "data": data });
+		self.with_body(url, body.to_string())
+	}
+	/// Serve an API error with the `status` and `message` for requests to `url`
+	pub fn with_error(self, url: impl Into<Box<str>>, status: StatusCode, message: &str) -> Self {
+		self.with_response(url, error_response(status, message))
+	}
+
+	/// Remove all responses and recorded requests
+	pub fn clear(&self) {
+		let mut state = self.state();
+		state.responses.clear();
+		state.requests.clear();
+	}
+
+	/// The requests received so far, in order
+	pub fn requests(&self) -> Vec<HttpRequest> {
+		self.state().requests.clone()
+	}
+
+	fn respond(&self, request: &HttpRequest) -> HttpResponse {
+		let mut state = self.state();
+		state.requests.push(request.clone());
+		state
+			.responses
+			.get(&request.url)
+			.cloned()
+			.unwrap_or_else(|| {
+				error_response(
+					StatusCode::NOT_FOUND,
+					&format!("No mock response for {}", request.url),
+				)
+			})
+	}
+}
+
+fn error_response(status: StatusCode, message: &str) -> HttpResponse {
+	let body = json!({ "status": "error", "data": message });
+	HttpResponse::new(status, body.to_string())
+}
+
+impl Transport for MockTransport {
+	fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+		let response = self.respond(request);
+		Box::pin(async move { Ok(response) })
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for MockTransport {
+	fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+		Ok(self.respond(request))
+	}
+}
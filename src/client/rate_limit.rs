@@ -0,0 +1,143 @@
+use std::{
+	sync::{Arc, LazyLock, Mutex},
+	time::{Duration, Instant},
+};
+
+use super::ApiKey;
+
+/// Amount of requests allowed per period of time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+	/// Requests allowed per `period`, also the maximum burst size
+	pub requests: u32,
+	/// The period the limit applies to
+	pub period: Duration,
+}
+
+impl RateLimit {
+	/// Published limit of requests without an API key
+	pub const ANONYMOUS: Self = Self::new(500, Duration::from_secs(10 * 60));
+	/// Published limit of requests with an API key
+	pub const KEYED: Self = Self::new(1000, Duration::from_secs(10 * 60));
+
+	/// Construct a limit of `requests` per `period`
+	pub const fn new(requests: u32, period: Duration) -> Self {
+		Self { requests, period }
+	}
+	/// The published limit depending on whether an API key is used
+	pub const fn published(keyed: bool) -> Self {
+		if keyed { Self::KEYED } else { Self::ANONYMOUS }
+	}
+}
+
+#[derive(Debug)]
+struct Bucket {
+	/// Available tokens, negative when requests are queued
+	tokens: f64,
+	last_refill: Instant,
+}
+
+/// Token bucket rate limiter
+///
+/// Requests wait for a free token instead of being throttled by the API.
+/// Clones share the same bucket, so a single limiter can be passed to
+/// several clients (including blocking ones) to respect one common limit.
+///
+/// # Examples
+/// ```
+/// use mcsr_ranked_api::client::{RankedClient, RateLimit, RateLimiter};
+///
+/// let limiter = RateLimiter::new(RateLimit::ANONYMOUS);
+/// let client_1 = RankedClient::builder().rate_limiter(limiter.clone()).build();
+/// let client_2 = RankedClient::builder().rate_limiter(limiter).build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+	limit: RateLimit,
+	bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+	/// Construct a limiter with a full bucket
+	pub fn new(limit: RateLimit) -> Self {
+		Self {
+			limit,
+			bucket: Arc::new(Mutex::new(Bucket {
+				tokens: f64::from(limit.requests.max(1)),
+				last_refill: Instant::now(),
+			})),
+		}
+	}
+
+	/// The limit being enforced
+	pub fn limit(&self) -> RateLimit {
+		self.limit
+	}
+
+	/// Take a token, returning how long to wait before the request may be sent
+	pub(crate) fn reserve(&self) -> Duration {
+		let capacity = f64::from(self.limit.requests.max(1));
+		let period = self.limit.period.as_secs_f64();
+		let per_second = if period > 0.0 {
+			capacity / period
+		} else {
+			f64::INFINITY
+		};
+
+		let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+		let now = Instant::now();
+		let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+		bucket.tokens = (bucket.tokens + elapsed * per_second).min(capacity);
+		bucket.last_refill = now;
+		bucket.tokens -= 1.0;
+
+		if bucket.tokens >= 0.0 {
+			Duration::ZERO
+		} else {
+			Duration::from_secs_f64(-bucket.tokens / per_second)
+		}
+	}
+
+	/// Give back a reserved token which was not used
+	fn release(&self) {
+		let capacity = f64::from(self.limit.requests.max(1));
+		let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+		bucket.tokens = (bucket.tokens + 1.0).min(capacity);
+	}
+
+	/// Wait until a request may be sent
+	///
+	/// The token is returned to the bucket if the future is dropped before it completes
+	pub async fn acquire(&self) {
+		let wait = self.reserve();
+		if !wait.is_zero() {
+			let reservation = Reservation(self);
+			tokio::time::sleep(wait).await;
+			std::mem::forget(reservation);
+		}
+	}
+
+	/// Synchronously wait until a request may be sent
+	pub fn acquire_blocking(&self) {
+		let wait = self.reserve();
+		if !wait.is_zero() {
+			std::thread::sleep(wait);
+		}
+	}
+}
+
+/// Token reserved by [`RateLimiter::acquire`], released when dropped while waiting
+struct Reservation<'a>(&'a RateLimiter);
+
+impl Drop for Reservation<'_> {
+	fn drop(&mut self) {
+		self.0.release();
+	}
+}
+
+/// Limiter shared by the default clients
+pub(crate) fn default_rate_limiter() -> &'static RateLimiter {
+	static LIMITER: LazyLock<RateLimiter> =
+		LazyLock::new(|| RateLimiter::new(RateLimit::published(ApiKey::from_env().is_some())));
+	&LIMITER
+}
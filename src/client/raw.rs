@@ -0,0 +1,47 @@
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+#[cfg(feature = "serialize")]
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// Data of a response decoded into `T`, along with the untouched data it was decoded from
+///
+/// Returned by [`super::RankedClient::get_with_raw`] to read fields this crate does not model yet.
+/// The body is parsed once, `T` is decoded from the raw data.
+/// Responses decoded into it are not reported to the schema drift hook, as no field is lost
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithRaw<T> {
+	pub value: T,
+	/// The `data` field of the response
+	pub raw: Value,
+}
+
+impl<T> WithRaw<T> {
+	/// The typed value and the raw data
+	pub fn into_parts(self) -> (T, Value) {
+		(self.value, self.raw)
+	}
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for WithRaw<T> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let raw = Value::deserialize(deserializer)?;
+		let value = serde_path_to_error::deserialize(&raw).map_err(|err| {
+			serde::de::Error::custom(format_args!("{}: {}", err.path(), err.inner()))
+		})?;
+		Ok(Self { value, raw })
+	}
+}
+
+/// Serializes as the raw data
+#[cfg(feature = "serialize")]
+impl<T> Serialize for WithRaw<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.raw.serialize(serializer)
+	}
+}
@@ -0,0 +1,178 @@
+use std::{
+	hash::{BuildHasher, RandomState},
+	time::Duration,
+};
+
+use reqwest::{
+	StatusCode,
+	header::{HeaderMap, RETRY_AFTER},
+};
+
+use crate::Error;
+
+/// Policy for retrying failed requests
+///
+/// Connection errors, timeouts, HTTP 5xx and HTTP 429 responses are retried
+/// with exponential backoff. The `Retry-After` header is honored when present,
+/// up to `max_delay`.
+///
+/// Note: this struct supports the builder pattern
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use mcsr_ranked_api::client::RetryPolicy;
+///
+/// let policy = RetryPolicy::default()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+	/// Maximum amount of attempts, including the first one
+	pub max_attempts: u32,
+	/// Delay before the first retry, doubled for every following retry
+	pub base_delay: Duration,
+	/// Upper bound of the backoff delay and of the `Retry-After` delay
+	pub max_delay: Duration,
+	/// Whether to randomize the delays to avoid synchronized retries
+	pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(30),
+			jitter: true,
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Policy that never retries
+	pub fn none() -> Self {
+		Self {
+			max_attempts: 1,
+			..Default::default()
+		}
+	}
+	/// Set the `max_attempts` field
+	pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts;
+		self
+	}
+	/// Set the `base_delay` field
+	pub fn base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+	/// Set the `max_delay` field
+	pub fn max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+	/// Set the `jitter` field
+	pub fn jitter(mut self, jitter: bool) -> Self {
+		self.jitter = jitter;
+		self
+	}
+
+	/// Backoff delay after the given failed `attempt`, 1-indexed
+	pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+		let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+		let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+		if !self.jitter || delay.is_zero() {
+			return delay;
+		}
+		// Equal jitter: half of the delay is fixed, the other half is random
+		let half = delay / 2;
+		let random = RandomState::new().hash_one(attempt);
+		half + Duration::from_nanos(random % (half.as_nanos() as u64).max(1))
+	}
+
+	/// Delay before retrying after the `attempt` ended with `failure`,
+	/// `None` if the request should not be retried
+	pub(crate) fn retry_delay(&self, attempt: u32, failure: &Failure) -> Option<Duration> {
+		if !failure.retryable || attempt >= self.max_attempts {
+			return None;
+		}
+		Some(match failure.retry_after {
+			Some(retry_after) => retry_after.min(self.max_delay),
+			None => self.backoff(attempt),
+		})
+	}
+}
+
+/// A failed request attempt
+#[derive(Debug)]
+pub(crate) struct Failure {
+	pub(crate) error: Error,
+	pub(crate) retryable: bool,
+	pub(crate) retry_after: Option<Duration>,
+}
+
+impl Failure {
+	/// Failure which is not worth retrying
+	pub(crate) fn fatal(error: Error) -> Self {
+		Self {
+			error,
+			retryable: false,
+			retry_after: None,
+		}
+	}
+
+	/// The error to return to the caller after `attempts` attempts
+	///
+	/// Only retryable failures are reported as exhausted retries
+	pub(crate) fn into_error(self, attempts: u32) -> Error {
+		if attempts > 1 && self.retryable {
+			Error::RetriesExhausted {
+				attempts,
+				last: Box::new(self.error),
+			}
+		} else {
+			self.error
+		}
+	}
+}
+
+impl From<Error> for Failure {
+	fn from(error: Error) -> Self {
+		let retryable = match &error {
+			Error::Reqwest(error) => {
+				error.is_connect()
+					|| error.is_timeout()
+					|| error.is_request()
+					|| error.is_body()
+					|| error.status().is_some_and(is_transient)
+			}
+			// Custom transports mostly fail on the network
+			Error::Transport(_) => true,
+			_ => false,
+		};
+		Self {
+			error,
+			retryable,
+			retry_after: None,
+		}
+	}
+}
+
+/// Whether a response with the `status` is worth retrying
+pub(crate) fn is_transient(status: StatusCode) -> bool {
+	status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parse the `Retry-After` header given in seconds
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+	headers
+		.get(RETRY_AFTER)?
+		.to_str()
+		.ok()?
+		.trim()
+		.parse()
+		.ok()
+		.map(Duration::from_secs)
+}
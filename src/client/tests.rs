@@ -0,0 +1,751 @@
+use std::time::Duration;
+
+use super::{DEFAULT_BASE_URL, RankedClient};
+
+#[test]
+fn builder() {
+	let client = RankedClient::builder()
+		.timeout(Duration::from_secs(10))
+		.connect_timeout(Duration::from_secs(5))
+		.user_agent("mcsr-ranked-api-tests")
+		.build();
+	assert!(client.is_ok());
+}
+
+#[test]
+fn base_url() {
+	assert_eq!(RankedClient::new().base_url(), DEFAULT_BASE_URL);
+
+	let client = RankedClient::builder()
+		.base_url("http://localhost:8080/mirror/".parse().unwrap())
+		.build()
+		.unwrap();
+	assert_eq!(client.base_url(), "http://localhost:8080/mirror");
+}
+
+#[test]
+fn invalid_user_agent() {
+	let client = RankedClient::builder().user_agent("invalid\nagent").build();
+	assert!(client.is_err());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn blocking_builder() {
+	use super::blocking::BlockingRankedClient;
+
+	let client = BlockingRankedClient::builder()
+		.timeout(Duration::from_secs(10))
+		.build();
+	assert!(client.is_ok());
+}
+
+#[test]
+fn api_key() {
+	use reqwest::StatusCode;
+
+	use super::{ApiKey, ClientConfig};
+	use crate::Error;
+
+	let key = ApiKey::new("secret-key");
+	assert_eq!(key.as_str(), "secret-key");
+	assert!(!format!("{key:?}").contains("secret-key"));
+
+	let client = RankedClient::builder()
+		.api_key("secret-key")
+		.build()
+		.unwrap();
+	assert!(client.has_api_key());
+	assert!(!format!("{client:?}").contains("secret-key"));
+	assert!(!RankedClient::new().has_api_key());
+
+	let config = ClientConfig {
+		api_key: Some(key),
+		..Default::default()
+	};
+	assert_eq!(
+		config.check_status(StatusCode::UNAUTHORIZED),
+		Err(Error::InvalidApiKey)
+	);
+	assert_eq!(config.check_status(StatusCode::OK), Ok(()));
+	assert_eq!(
+		ClientConfig::default().check_status(StatusCode::UNAUTHORIZED),
+		Ok(())
+	);
+}
+
+mod rate_limit {
+	use std::time::{Duration, Instant};
+
+	use crate::client::{RankedClient, RateLimit, RateLimiter};
+
+	#[test]
+	fn reserve() {
+		let limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(10)));
+		assert_eq!(limiter.reserve(), Duration::ZERO);
+		assert_eq!(limiter.reserve(), Duration::ZERO);
+
+		let wait = limiter.reserve();
+		assert!(wait > Duration::from_secs(4) && wait <= Duration::from_secs(5));
+		let queued = limiter.reserve();
+		assert!(queued > Duration::from_secs(9) && queued <= Duration::from_secs(10));
+	}
+
+	#[test]
+	fn shared_between_clones() {
+		let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_secs(10)));
+		let clone = limiter.clone();
+		assert_eq!(limiter.reserve(), Duration::ZERO);
+		assert!(clone.reserve() > Duration::ZERO);
+	}
+
+	#[test]
+	fn acquire_blocking() {
+		let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_millis(50)));
+		let start = Instant::now();
+		limiter.acquire_blocking();
+		limiter.acquire_blocking();
+		assert!(start.elapsed() >= Duration::from_millis(40));
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn dropped_acquire() {
+		let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_secs(10)));
+		limiter.acquire().await;
+		let waiting = tokio::time::timeout(Duration::from_secs(1), limiter.acquire()).await;
+		assert!(waiting.is_err());
+
+		// Only the token of the completed acquire is still used
+		let wait = limiter.reserve();
+		assert!(wait > Duration::from_secs(8) && wait <= Duration::from_secs(10));
+	}
+
+	#[test]
+	fn client_limits() {
+		let anonymous = RankedClient::new();
+		assert_eq!(
+			anonymous.rate_limiter().map(RateLimiter::limit),
+			Some(RateLimit::ANONYMOUS)
+		);
+
+		let keyed = RankedClient::builder().api_key("key").build().unwrap();
+		assert_eq!(
+			keyed.rate_limiter().map(RateLimiter::limit),
+			Some(RateLimit::KEYED)
+		);
+
+		let unlimited = RankedClient::builder().no_rate_limit().build().unwrap();
+		assert!(unlimited.rate_limiter().is_none());
+
+		let wrapped = RankedClient::from(reqwest::Client::new());
+		assert_eq!(
+			wrapped.rate_limiter().map(RateLimiter::limit),
+			Some(RateLimit::ANONYMOUS)
+		);
+		#[cfg(feature = "blocking")]
+		{
+			use crate::BlockingRankedClient;
+
+			let wrapped = BlockingRankedClient::from(reqwest::blocking::Client::new());
+			assert_eq!(
+				wrapped.rate_limiter().map(RateLimiter::limit),
+				Some(RateLimit::ANONYMOUS)
+			);
+		}
+	}
+}
+
+mod retry {
+	use std::time::Duration;
+
+	use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+	use serde_json::Value;
+
+	use crate::{
+		ApiError, Error,
+		client::{
+			RetryPolicy,
+			retry::{Failure, retry_after},
+		},
+	};
+
+	fn transient() -> Failure {
+		Failure {
+			error: Error::Api(ApiError::new(None, Value::Null)),
+			retryable: true,
+			retry_after: None,
+		}
+	}
+
+	#[test]
+	fn backoff() {
+		let policy = RetryPolicy::default()
+			.base_delay(Duration::from_millis(100))
+			.max_delay(Duration::from_millis(350))
+			.jitter(false);
+		assert_eq!(policy.backoff(1), Duration::from_millis(100));
+		assert_eq!(policy.backoff(2), Duration::from_millis(200));
+		assert_eq!(policy.backoff(3), Duration::from_millis(350));
+		assert_eq!(policy.backoff(40), Duration::from_millis(350));
+
+		let jittered = policy.jitter(true);
+		for attempt in 1..5 {
+			let delay = jittered.backoff(attempt);
+			let max = policy.backoff(attempt);
+			assert!(delay >= max / 2 && delay <= max);
+		}
+	}
+
+	#[test]
+	fn retry_delay() {
+		let policy = RetryPolicy::default().max_attempts(3).jitter(false);
+		assert_eq!(policy.retry_delay(1, &transient()), Some(policy.base_delay));
+		assert_eq!(policy.retry_delay(3, &transient()), None);
+		assert_eq!(
+			policy.retry_delay(
+				1,
+				&Failure::fatal(Error::Api(ApiError::new(None, Value::Null)))
+			),
+			None
+		);
+
+		let throttled = Failure {
+			retry_after: Some(Duration::from_secs(7)),
+			..transient()
+		};
+		assert_eq!(
+			policy.retry_delay(1, &throttled),
+			Some(Duration::from_secs(7))
+		);
+		assert_eq!(RetryPolicy::none().retry_delay(1, &transient()), None);
+
+		let stalled = Failure {
+			retry_after: Some(Duration::from_secs(24 * 60 * 60)),
+			..transient()
+		};
+		assert_eq!(policy.retry_delay(1, &stalled), Some(policy.max_delay));
+	}
+
+	#[test]
+	fn final_error() {
+		assert_eq!(
+			transient().into_error(1),
+			Error::Api(ApiError::new(None, Value::Null))
+		);
+		assert_eq!(
+			transient().into_error(3),
+			Error::RetriesExhausted {
+				attempts: 3,
+				last: Box::new(Error::Api(ApiError::new(None, Value::Null))),
+			}
+		);
+		assert_eq!(
+			Failure::fatal(Error::Api(ApiError::new(None, Value::Null))).into_error(2),
+			Error::Api(ApiError::new(None, Value::Null))
+		);
+	}
+
+	#[test]
+	fn retry_after_header() {
+		let mut headers = HeaderMap::new();
+		assert_eq!(retry_after(&headers), None);
+		headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+		assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+		headers.insert(
+			RETRY_AFTER,
+			HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+		);
+		assert_eq!(retry_after(&headers), None);
+	}
+}
+
+mod transport {
+	use reqwest::StatusCode;
+	use serde_json::{Value, json};
+
+	use crate::{
+		ApiErrorKind, Error,
+		client::{HttpResponse, MockTransport, RankedClient},
+	};
+
+	const URL: &str = "https://api.mcsrranked.com/test?count=1";
+
+	fn client(mock: &MockTransport) -> RankedClient {
+		RankedClient::builder()
+			.api_key("secret-key")
+			.no_retry()
+			.transport(mock.clone())
+			.build()
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn mock() {
+		let mock = MockTransport::new().with_data(URL, json!({ "id": 1 }));
+		let client = client(&mock);
+
+		let value: Value = client
+			.request("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await
+			.unwrap();
+		assert_eq!(value, json!({ "id": 1 }));
+
+		let requests = mock.requests();
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].url.as_ref(), URL);
+		assert_eq!(requests[0].headers["API-Key"], "secret-key");
+		assert!(!format!("{mock:?}").contains("secret-key"));
+	}
+
+	#[tokio::test]
+	async fn mock_errors() {
+		let mock = MockTransport::new()
+			.with_error(URL, StatusCode::BAD_REQUEST, "Invalid count")
+			.with_response(
+				"https://api.mcsrranked.com/down",
+				HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE, "<html></html>"),
+			);
+		let client = client(&mock);
+
+		let error = client
+			.request::<Value, _, _>("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await
+			.unwrap_err();
+		assert_eq!(error.api_error_kind(), Some(ApiErrorKind::BadParameter));
+
+		let error = client
+			.request::<Value, _, _>("down", &[] as &[&str], None::<&()>)
+			.await
+			.unwrap_err();
+		assert_eq!(error.api_error_kind(), Some(ApiErrorKind::ServerError));
+
+		let error = client
+			.request::<Value, _, _>("missing", &[] as &[&str], None::<&()>)
+			.await
+			.unwrap_err();
+		assert_eq!(error.api_error_kind(), Some(ApiErrorKind::NotFound));
+
+		let error = client
+			.request::<u32, _, _>("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await;
+		assert!(matches!(error, Err(Error::Api(_))));
+	}
+
+	#[tokio::test]
+	async fn schema_drift() {
+		use std::sync::{Arc, Mutex};
+
+		use crate::{client::SchemaDrift, user::UserProfile};
+
+		let profile = json!({
+			"uuid": "79635c3dbf634a228bf44544cc7c0d27",
+			"nickname": "LaysarOwO",
+			"roleType": 0,
+			"eloRate": 1226,
+			"eloRank": 333,
+			"country": null,
+		});
+		let mut drifted = profile.clone();
+		drifted["newField"] = json!(1);
+		drifted["badges"] = json!({ "first": true });
+		let mock = MockTransport::new()
+			.with_data(URL, json!([profile, drifted]))
+			.with_data("https://api.mcsrranked.com/known", profile);
+
+		let drifts = Arc::new(Mutex::new(Vec::new()));
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock)
+			.on_schema_drift({
+				let drifts = drifts.clone();
+				move |drift: &SchemaDrift| drifts.lock().unwrap().push(drift.clone())
+			})
+			.build()
+			.unwrap();
+
+		let profiles: Box<[UserProfile]> = client
+			.request("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await
+			.unwrap();
+		assert_eq!(profiles[0], profiles[1]);
+		let _: UserProfile = client
+			.request("known", &[] as &[&str], None::<&()>)
+			.await
+			.unwrap();
+
+		let drifts = drifts.lock().unwrap();
+		assert_eq!(drifts.len(), 1);
+		assert_eq!(drifts[0].url.as_ref(), URL);
+		assert!(drifts[0].type_name.ends_with("UserProfile]>"));
+		assert_eq!(
+			drifts[0].paths.as_ref(),
+			[Box::from("data[1].badges"), Box::from("data[1].newField")]
+		);
+	}
+
+	#[cfg(all(feature = "serialize", feature = "matches"))]
+	#[tokio::test]
+	async fn flattened_schema_drift() {
+		use std::sync::{Arc, Mutex};
+
+		use crate::{
+			client::SchemaDrift, game::tests::MATCH_1524115, user::identifier::UserIdentifier,
+		};
+
+		let fixture: Value = serde_json::from_str(include_str!(
+			"../../fixtures/users_79635c3d-bf63-4a22-8bf4-4544cc7c0d27.json"
+		))
+		.unwrap();
+		let mut user = fixture["body"]["data"].clone();
+		user["newField"] = json!(1);
+		let mut info: Value = serde_json::from_str(MATCH_1524115).unwrap();
+		info["newField"] = json!("value");
+		let mock = MockTransport::new()
+			.with_data("https://api.mcsrranked.com/users/LaysarOwO", user)
+			.with_data("https://api.mcsrranked.com/matches/1524115", info);
+
+		let drifts = Arc::new(Mutex::new(Vec::new()));
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock)
+			.on_schema_drift({
+				let drifts = drifts.clone();
+				move |drift: &SchemaDrift| drifts.lock().unwrap().push(drift.paths.clone())
+			})
+			.build()
+			.unwrap();
+		client
+			.get_user(&UserIdentifier::Nickname("LaysarOwO"), None)
+			.await
+			.unwrap();
+		client.get_match(1524115).await.unwrap();
+
+		let drifts = drifts.lock().unwrap();
+		assert_eq!(
+			*drifts,
+			[
+				Box::from([Box::from("data.newField")]),
+				Box::from([Box::from("data.newField")])
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn with_raw() {
+		#[derive(Debug, PartialEq, serde::Deserialize)]
+		struct Item {
+			id: u32,
+		}
+
+		let mock = MockTransport::new()
+			.with_data(URL, json!({ "id": 1, "newField": [1, 2] }))
+			.with_data("https://api.mcsrranked.com/bad", json!({ "id": "1" }))
+			.with_error(
+				"https://api.mcsrranked.com/missing",
+				StatusCode::NOT_FOUND,
+				"Not found",
+			);
+		let client = client(&mock);
+
+		let (item, raw) = client
+			.get_with_raw::<Item>("test", Some(&json!({ "count": 1 })))
+			.await
+			.unwrap()
+			.into_parts();
+		assert_eq!(item, Item { id: 1 });
+		assert_eq!(raw["newField"], json!([1, 2]));
+		assert_eq!(
+			client
+				.get_raw("test", Some(&json!({ "count": 1 })))
+				.await
+				.unwrap(),
+			raw
+		);
+
+		let error = client
+			.get_with_raw::<Item>("bad", None::<&()>)
+			.await
+			.unwrap_err();
+		let Error::Decode(error) = error else {
+			panic!("Expected a decode error, got {error:?}");
+		};
+		assert!(error.message.starts_with("id: invalid type"));
+		assert!(client.get_raw("missing", None::<&()>).await.is_err());
+	}
+
+	#[cfg(feature = "blocking")]
+	#[test]
+	fn mock_blocking() {
+		use crate::client::blocking::BlockingRankedClient;
+
+		let mock = MockTransport::new().with_body(URL, r#"{"status":"success","data":1}"#);
+		let client = BlockingRankedClient::builder()
+			.transport(mock.clone())
+			.build()
+			.unwrap();
+		let value: u32 = client
+			.request("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.unwrap();
+		assert_eq!(value, 1);
+		assert!(mock.requests()[0].headers.is_empty());
+	}
+
+	#[tokio::test]
+	async fn cassette() {
+		use std::fs;
+
+		use crate::client::{Cassette, CassetteMode};
+
+		let dir = std::env::temp_dir().join(format!("mcsr-ranked-cassette-{}", std::process::id()));
+		let mock = MockTransport::new()
+			.with_data(URL, json!({ "id": 1 }))
+			.with_response(
+				"https://api.mcsrranked.com/down",
+				HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE, "<html></html>"),
+			);
+		let recorder = RankedClient::builder()
+			.no_retry()
+			.transport(Cassette::new(&dir, CassetteMode::Record, mock.clone()))
+			.build()
+			.unwrap();
+		let recorded: Value = recorder
+			.request("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await
+			.unwrap();
+		let _ = recorder
+			.request::<Value, _, _>("down", &[] as &[&str], None::<&()>)
+			.await;
+		assert!(dir.join("test-count=1.json").is_file());
+		assert!(dir.join("down.json").is_file());
+
+		mock.clear();
+		let player = RankedClient::builder()
+			.no_retry()
+			.transport(Cassette::new(&dir, CassetteMode::Replay, mock.clone()))
+			.build()
+			.unwrap();
+		let replayed: Value = player
+			.request("test", &[] as &[&str], Some(&json!({ "count": 1 })))
+			.await
+			.unwrap();
+		assert_eq!(replayed, recorded);
+		let error = player
+			.request::<Value, _, _>("down", &[] as &[&str], None::<&()>)
+			.await
+			.unwrap_err();
+		assert_eq!(error.api_error_kind(), Some(ApiErrorKind::ServerError));
+		assert!(mock.requests().is_empty());
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
+
+mod cache {
+	use std::{
+		num::NonZeroUsize,
+		time::{Duration, Instant},
+	};
+
+	use reqwest::StatusCode;
+	use serde_json::{Value, json};
+
+	use crate::client::{
+		ApiKey, CachePolicy, CacheStats, CachedEndpoint, HttpResponse, MockTransport, RankedClient,
+		ResponseCache, cache::CacheKey,
+	};
+
+	const BASE_URL: &str = "https://api.mcsrranked.com";
+	const USER_URL: &str = "https://api.mcsrranked.com/users/RED_LIME";
+	const LIVE_URL: &str = "https://api.mcsrranked.com/live";
+
+	fn client(mock: &MockTransport, cache: &ResponseCache) -> RankedClient {
+		RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.cache(cache.clone())
+			.build()
+			.unwrap()
+	}
+
+	async fn get(client: &RankedClient, path: &str) -> crate::Result<Value> {
+		client.get_raw(path, None::<&()>).await
+	}
+
+	#[test]
+	fn endpoints() {
+		assert_eq!(CachedEndpoint::of("matches/1524115"), CachedEndpoint::Match);
+		assert_eq!(
+			CachedEndpoint::of("/matches?count=20"),
+			CachedEndpoint::Matches
+		);
+		assert_eq!(
+			CachedEndpoint::of("users/doogile/versus/Feinberg/matches"),
+			CachedEndpoint::Matches
+		);
+		assert_eq!(
+			CachedEndpoint::of("users/doogile/versus/Feinberg"),
+			CachedEndpoint::Versus
+		);
+		assert_eq!(CachedEndpoint::of("users/doogile"), CachedEndpoint::User);
+		assert_eq!(
+			CachedEndpoint::of("users/doogile/seasons"),
+			CachedEndpoint::User
+		);
+		assert_eq!(
+			CachedEndpoint::of("record-leaderboard?season=7"),
+			CachedEndpoint::Leaderboard
+		);
+		assert_eq!(
+			CachedEndpoint::of("weekly-race/13"),
+			CachedEndpoint::WeeklyRace
+		);
+		assert_eq!(CachedEndpoint::of("live"), CachedEndpoint::Live);
+		assert_eq!(CachedEndpoint::of("unknown/path"), CachedEndpoint::Other);
+	}
+
+	#[tokio::test]
+	async fn hits_and_misses() {
+		let mock = MockTransport::new()
+			.with_data(USER_URL, json!({ "nickname": "RED_LIME" }))
+			.with_data(LIVE_URL, json!({ "players": 0 }))
+			.with_error(
+				"https://api.mcsrranked.com/users/missing",
+				StatusCode::NOT_FOUND,
+				"User is not found",
+			);
+		let cache = ResponseCache::default();
+		let client = client(&mock, &cache);
+
+		let first = get(&client, "users/RED_LIME").await.unwrap();
+		let second = get(&client, "users/RED_LIME").await.unwrap();
+		assert_eq!(first, second);
+		assert_eq!(mock.requests().len(), 1);
+		assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+		assert_eq!(cache.stats().hit_rate(), Some(0.5));
+
+		get(&client, "live").await.unwrap();
+		get(&client, "live").await.unwrap();
+		assert_eq!(mock.requests().len(), 3);
+		assert!(get(&client, "users/missing").await.is_err());
+		assert!(get(&client, "users/missing").await.is_err());
+		assert_eq!(mock.requests().len(), 5);
+		assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3 });
+		assert_eq!(cache.len(), 1);
+
+		assert!(cache.invalidate("users/RED_LIME", None::<&()>));
+		assert!(!cache.invalidate("users/RED_LIME", None::<&()>));
+		get(&client, "users/RED_LIME").await.unwrap();
+		assert_eq!(mock.requests().len(), 6);
+		cache.invalidate_endpoint(CachedEndpoint::User);
+		assert!(cache.is_empty());
+
+		cache.reset_stats();
+		assert_eq!(cache.stats().hit_rate(), None);
+	}
+
+	#[tokio::test]
+	async fn api_keys() {
+		let mock = MockTransport::new().with_data(
+			"https://api.mcsrranked.com/users/RED_LIME?season=7",
+			json!({ "nickname": "RED_LIME" }),
+		);
+		let cache = ResponseCache::default();
+		let anonymous = client(&mock, &cache);
+		let keyed = |key: &str| {
+			RankedClient::builder()
+				.no_retry()
+				.transport(mock.clone())
+				.cache(cache.clone())
+				.api_key(ApiKey::new(key))
+				.build()
+				.unwrap()
+		};
+		let params = Some(&json!({ "season": 7 }));
+
+		for client in [&anonymous, &keyed("first"), &keyed("first"), &keyed("second")] {
+			client.get_raw("users/RED_LIME", params).await.unwrap();
+		}
+		assert_eq!(mock.requests().len(), 3);
+		assert_eq!(cache.len(), 3);
+
+		assert!(!cache.invalidate("users/RED_LIME", None::<&()>));
+		assert!(cache.invalidate("/users/RED_LIME", params));
+		assert!(cache.is_empty());
+	}
+
+	#[tokio::test]
+	async fn hits_not_reported() {
+		use std::sync::{
+			Arc,
+			atomic::{AtomicUsize, Ordering},
+		};
+
+		#[derive(serde::Deserialize)]
+		#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+		struct User {
+			#[allow(dead_code)]
+			nickname: String,
+		}
+
+		let mock = MockTransport::new()
+			.with_data(USER_URL, json!({ "nickname": "RED_LIME", "newField": 1 }));
+		let cache = ResponseCache::default();
+		let drifts = Arc::new(AtomicUsize::new(0));
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.cache(cache.clone())
+			.on_schema_drift({
+				let drifts = drifts.clone();
+				move |_: &crate::client::SchemaDrift| {
+					drifts.fetch_add(1, Ordering::Relaxed);
+				}
+			})
+			.build()
+			.unwrap();
+
+		for _ in 0..2 {
+			let _: User = client
+				.request("users/RED_LIME", &[] as &[&str], None::<&()>)
+				.await
+				.unwrap();
+		}
+		assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+		assert_eq!(drifts.load(Ordering::Relaxed), 1);
+	}
+
+	#[test]
+	fn expiry_and_eviction() {
+		let cache = ResponseCache::new(
+			CachePolicy::default()
+				.capacity(NonZeroUsize::new(2).unwrap())
+				.ttl(CachedEndpoint::User, Duration::from_secs(10)),
+		);
+		let response = HttpResponse::new(StatusCode::OK, "{}");
+		let now = Instant::now();
+		let key = |path: &str| CacheKey::new(&format!("{BASE_URL}/{path}"), BASE_URL, None);
+
+		cache.insert_at(key("users/a"), response.clone(), now);
+		assert!(
+			cache
+				.get_at(&key("users/a"), now + Duration::from_secs(9))
+				.is_some()
+		);
+		assert!(
+			cache
+				.get_at(&key("users/a"), now + Duration::from_secs(10))
+				.is_none()
+		);
+		assert!(cache.is_empty());
+
+		cache.insert_at(key("matches/1"), response.clone(), now);
+		cache.insert_at(key("matches/2"), response.clone(), now);
+		cache.get_at(&key("matches/1"), now);
+		cache.insert_at(key("matches/3"), response.clone(), now);
+		assert!(cache.get_at(&key("matches/1"), now).is_some());
+		assert!(cache.get_at(&key("matches/2"), now).is_none());
+
+		cache.insert_at(key("live"), response, now);
+		assert_eq!(cache.len(), 2);
+	}
+}
@@ -0,0 +1,94 @@
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use reqwest::{StatusCode, header::HeaderMap};
+
+use crate::Result;
+
+/// Future returned by [`Transport::send`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// GET request to be sent by a transport
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+	/// Full URL of the request, including the query
+	pub url: Box<str>,
+	/// Headers to send along, such as the `API-Key` header
+	pub headers: HeaderMap,
+}
+
+/// Response received by a transport
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+	pub status: StatusCode,
+	pub headers: HeaderMap,
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	/// Construct a response without headers
+	pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+		Self {
+			status,
+			headers: HeaderMap::new(),
+			body: body.into(),
+		}
+	}
+}
+
+/// Backend sending the HTTP requests of a [`super::RankedClient`]
+///
+/// Implemented for [`reqwest::Client`], which is used by default.
+/// Custom implementations can swap the HTTP library or serve canned responses,
+/// see [`super::MockTransport`].
+/// Errors of custom implementations should be reported as [`crate::Error::Transport`]
+pub trait Transport: Debug + Send + Sync {
+	/// Send the `request` and read the whole response
+	fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a>;
+}
+
+impl Transport for reqwest::Client {
+	fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+		Box::pin(async move {
+			let response = self
+				.get(&*request.url)
+				.headers(request.headers.clone())
+				.send()
+				.await?;
+			let status = response.status();
+			let headers = response.headers().clone();
+			let body = response.bytes().await?.into();
+			Ok(HttpResponse {
+				status,
+				headers,
+				body,
+			})
+		})
+	}
+}
+
+/// Backend sending the HTTP requests of a [`super::blocking::BlockingRankedClient`]
+///
+/// Blocking counterpart of [`Transport`], implemented for [`reqwest::blocking::Client`]
+#[cfg(feature = "blocking")]
+pub trait BlockingTransport: Debug + Send + Sync {
+	/// Send the `request` and read the whole response
+	fn send(&self, request: &HttpRequest) -> Result<HttpResponse>;
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTransport for reqwest::blocking::Client {
+	fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+		let response = self
+			.get(&*request.url)
+			.headers(request.headers.clone())
+			.send()?;
+		let status = response.status();
+		let headers = response.headers().clone();
+		let body = response.bytes()?.into();
+		Ok(HttpResponse {
+			status,
+			headers,
+			body,
+		})
+	}
+}
@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use uuid::Uuid;
+
+#[cfg(feature = "blocking")]
+use crate::client::blocking::BlockingRankedClient;
+use crate::{
+	Result,
+	client::RankedClient,
+	types::{Elo, EloChange, MatchId, Season},
+	user::identifier::UserIdentifier,
+};
+
+use super::{MatchInfo, requests::GetMatchesParams};
+
+/// Elo of a player after a single match
+///
+/// The elo rank after the match is left out on purpose, as it cannot be reconstructed:
+/// the elo updates of a match carry no rank and the `eloRank` of its players
+/// is their current rank at the time the match was fetched
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EloPoint {
+	pub date: DateTime<Utc>,
+	pub match_id: MatchId,
+	pub season: Season,
+	/// The elo after the match
+	pub elo: Elo,
+	/// The change caused by the match
+	pub change: Option<EloChange>,
+	/// Whether the match is an elo decay instead of a played match
+	pub decayed: bool,
+}
+
+/// Chronological series of a player's elo, reconstructed from their matches
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EloHistory {
+	pub player_uuid: Uuid,
+	/// The points, oldest first
+	pub points: Vec<EloPoint>,
+}
+
+impl EloHistory {
+	/// Construct the history of the player with `player_uuid` from their `matches`
+	///
+	/// The matches may be in any order, matches without an elo update of the player are skipped
+	pub fn from_matches<'a>(
+		player_uuid: Uuid,
+		matches: impl IntoIterator<Item = &'a MatchInfo>,
+	) -> Self {
+		let mut points: Vec<EloPoint> = matches
+			.into_iter()
+			.filter_map(|info| point(player_uuid, info))
+			.collect();
+		points.sort_by_key(|point| (point.date, point.match_id));
+		points.dedup_by_key(|point| point.match_id);
+		Self {
+			player_uuid,
+			points,
+		}
+	}
+
+	/// The points, oldest first
+	pub fn points(&self) -> &[EloPoint] {
+		&self.points
+	}
+	/// The most recent point
+	pub fn last(&self) -> Option<&EloPoint> {
+		self.points.last()
+	}
+	/// The first point with the highest elo
+	pub fn highest(&self) -> Option<&EloPoint> {
+		self.points.iter().rev().max_by_key(|point| point.elo)
+	}
+	/// The first point with the lowest elo
+	pub fn lowest(&self) -> Option<&EloPoint> {
+		self.points.iter().rev().min_by_key(|point| point.elo)
+	}
+	/// Points of played matches, skipping elo decays
+	pub fn played(&self) -> impl Iterator<Item = &EloPoint> + '_ {
+		self.points.iter().filter(|point| !point.decayed)
+	}
+	/// Total elo lost to decay
+	pub fn decay_loss(&self) -> i32 {
+		self.points
+			.iter()
+			.filter(|point| point.decayed)
+			.filter_map(|point| point.change)
+			.map(|change| -i32::from(change))
+			.sum()
+	}
+
+	/// The points split at season boundaries, oldest season first
+	pub fn seasons(&self) -> impl Iterator<Item = (Season, &[EloPoint])> + '_ {
+		self.points
+			.chunk_by(|lhs, rhs| lhs.season == rhs.season)
+			.map(|points| (points[0].season, points))
+	}
+	/// The points of a single `season`
+	pub fn season(&self, season: Season) -> &[EloPoint] {
+		self.seasons()
+			.find_map(|(other, points)| (other == season).then_some(points))
+			.unwrap_or_default()
+	}
+}
+
+fn point(player_uuid: Uuid, info: &MatchInfo) -> Option<EloPoint> {
+	let update = info
+		.elo_updates
+		.iter()
+		.find(|update| update.player_uuid == player_uuid)?;
+	Some(EloPoint {
+		date: info.date,
+		match_id: info.id,
+		season: info.season,
+		elo: update.elo?,
+		change: update.change,
+		decayed: info.decayed,
+	})
+}
+
+impl RankedClient {
+	/// GET the user's elo history by `identifier`, paging through their matches with `params`
+	///
+	/// The user is looked up first unless the `identifier` is a UUID.
+	/// Use the season of `params` to reconstruct a single season
+	pub async fn get_user_elo_history<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<EloHistory> {
+		let player_uuid = match identifier {
+			UserIdentifier::Uuid(uuid) => *uuid,
+			_ => self.get_user(identifier, None).await?.profile.uuid,
+		};
+		let matches: Vec<MatchInfo> = self
+			.stream_user_matches(identifier, params)
+			.try_collect()
+			.await?;
+		Ok(EloHistory::from_matches(player_uuid, &matches))
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the user's elo history by `identifier`, paging through their matches with `params`
+	///
+	/// The user is looked up first unless the `identifier` is a UUID.
+	/// Use the season of `params` to reconstruct a single season
+	pub fn get_user_elo_history<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<EloHistory> {
+		let player_uuid = match identifier {
+			UserIdentifier::Uuid(uuid) => *uuid,
+			_ => self.get_user(identifier, None)?.profile.uuid,
+		};
+		let matches: Vec<MatchInfo> = self
+			.iter_user_matches(identifier, params)
+			.collect::<Result<_>>()?;
+		Ok(EloHistory::from_matches(player_uuid, &matches))
+	}
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::timeline::TimelineEventKind;
+use crate::{types::Time, user::UserProfile};
+
+pub mod requests;
+#[cfg(test)]
+mod tests;
+
+/// Latest timeline event of a player in a live match
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveTimelineEvent {
+	pub time: Time,
+	#[serde(rename = "type")]
+	pub id: Box<str>,
+}
+impl LiveTimelineEvent {
+	/// The raw id of the event, such as `story.enter_the_nether`
+	pub fn id(&self) -> &str {
+		&self.id
+	}
+	/// The kind of the event, parsed from its id
+	pub fn kind(&self) -> TimelineEventKind {
+		TimelineEventKind::from(self.id())
+	}
+}
+
+/// Progress of a player in a live match
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LivePlayerProgress {
+	/// URL of the player's stream, if they are streaming the match
+	pub live_url: Option<Box<str>>,
+	/// The player's latest timeline event, `None` if there was none yet
+	pub timeline: Option<LiveTimelineEvent>,
+}
+impl LivePlayerProgress {
+	/// URL of the player's stream, if they are streaming the match
+	pub fn live_url(&self) -> Option<&str> {
+		self.live_url.as_deref()
+	}
+}
+
+/// Match which is currently being played
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveMatch {
+	/// Time elapsed since the start of the match
+	pub current_time: Time,
+	/// Users participating in the match
+	pub players: Box<[UserProfile]>,
+	/// Progress of the players, by UUID
+	///
+	/// Only players who allow showing their progress are included
+	#[serde(rename = "data", default)]
+	pub progress: HashMap<Uuid, LivePlayerProgress>,
+}
+impl LiveMatch {
+	/// Users participating in the match
+	pub fn players(&self) -> &[UserProfile] {
+		&self.players
+	}
+	/// Progress of the player with `uuid`, if it is shown
+	pub fn progress(&self, uuid: Uuid) -> Option<&LivePlayerProgress> {
+		self.progress.get(&uuid)
+	}
+}
+
+/// Online players and matches currently being played
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInfo {
+	/// Amount of players currently online
+	#[serde(rename = "players")]
+	pub online_players: u32,
+	/// The matches currently being played
+	pub live_matches: Box<[LiveMatch]>,
+}
+impl LiveInfo {
+	/// The matches currently being played
+	pub fn live_matches(&self) -> &[LiveMatch] {
+		&self.live_matches
+	}
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "blocking")]
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+};
+
+use super::LiveInfo;
+
+const BASE_PATH: &str = "live";
+
+impl RankedClient {
+	/// GET the online players and live matches
+	///
+	/// The endpoint requires an API key, without one [`crate::Error::ApiKeyRequired`]
+	/// is returned and no request is sent
+	pub async fn get_live(&self) -> Result<LiveInfo> {
+		self.require_api_key()?;
+		self.request(BASE_PATH, &[] as &[&str], None::<&()>).await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the online players and live matches
+	///
+	/// The endpoint requires an API key, without one [`crate::Error::ApiKeyRequired`]
+	/// is returned and no request is sent
+	pub fn get_live(&self) -> Result<LiveInfo> {
+		self.require_api_key()?;
+		self.request(BASE_PATH, &[] as &[&str], None::<&()>)
+	}
+}
+
+impl LiveInfo {
+	/// GET the online players and live matches
+	///
+	/// Uses the API key from [`crate::client::API_KEY_ENV_VAR`]
+	pub async fn get() -> Result<Self> {
+		default_client().get_live().await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl LiveInfo {
+	/// Synchronously GET the online players and live matches
+	///
+	/// Uses the API key from [`crate::client::API_KEY_ENV_VAR`]
+	pub fn get_blocking() -> Result<Self> {
+		default_blocking_client().get_live()
+	}
+}
@@ -0,0 +1,72 @@
+use serde_json::json;
+use uuid::uuid;
+
+use super::LiveInfo;
+use crate::game::timeline::TimelineEventKind;
+use crate::{
+	Error,
+	client::{MockTransport, RankedClient},
+	types::Time,
+};
+
+const JSON: &str = r#"{"players":1234,"liveMatches":[{"currentTime":421337,"players":[{"uuid":"bbc886da1b024739b4b80f1542e9f61d","nickname":"RED_LIME","roleType":3,"eloRate":2118,"eloRank":5,"country":"us"},{"uuid":"7665f76f431b41c6b321bea16aff913b","nickname":"lowk3y_","roleType":0,"eloRate":2037,"eloRank":11,"country":"us"}],"data":{"bbc886da1b024739b4b80f1542e9f61d":{"liveUrl":"https://twitch.tv/red_lime","timeline":{"time":398211,"type":"story.enter_the_nether"}},"7665f76f431b41c6b321bea16aff913b":{"liveUrl":null,"timeline":null}}}]}"#;
+
+#[test]
+fn live() {
+	let live: LiveInfo = serde_json::from_str(JSON).unwrap();
+	assert_eq!(live.online_players, 1234);
+	assert_eq!(live.live_matches().len(), 1);
+
+	let live_match = &live.live_matches()[0];
+	assert_eq!(live_match.current_time, Time::new(421337));
+	assert_eq!(live_match.players().len(), 2);
+
+	let red_lime = live_match
+		.progress(uuid!("bbc886da1b024739b4b80f1542e9f61d"))
+		.unwrap();
+	assert_eq!(red_lime.live_url(), Some("https://twitch.tv/red_lime"));
+	let event = red_lime.timeline.as_ref().unwrap();
+	assert_eq!(event.time, Time::new(398211));
+	assert_eq!(event.kind(), TimelineEventKind::StoryEnterTheNether);
+	assert_eq!(event.id(), "story.enter_the_nether");
+
+	let lowkey = live_match
+		.progress(uuid!("7665f76f431b41c6b321bea16aff913b"))
+		.unwrap();
+	assert_eq!(lowkey.live_url(), None);
+	assert_eq!(lowkey.timeline, None);
+}
+
+#[test]
+fn live_without_progress() {
+	let live: LiveInfo = serde_json::from_value(json!({
+		"players": 0,
+		"liveMatches": [{ "currentTime": 0, "players": [] }],
+	}))
+	.unwrap();
+	assert!(live.live_matches()[0].progress.is_empty());
+}
+
+#[tokio::test]
+async fn live_requires_api_key() {
+	let data: serde_json::Value = serde_json::from_str(JSON).unwrap();
+	let mock = MockTransport::new().with_data("https://api.mcsrranked.com/live", data);
+
+	let anonymous = RankedClient::builder()
+		.no_retry()
+		.transport(mock.clone())
+		.build()
+		.unwrap();
+	assert_eq!(anonymous.get_live().await, Err(Error::ApiKeyRequired));
+	assert!(mock.requests().is_empty());
+
+	let keyed = RankedClient::builder()
+		.api_key("secret-key")
+		.no_retry()
+		.transport(mock.clone())
+		.build()
+		.unwrap();
+	let live = keyed.get_live().await.unwrap();
+	assert_eq!(live.online_players, 1234);
+	assert_eq!(mock.requests().len(), 1);
+}
@@ -2,23 +2,36 @@ use chrono::{DateTime, Utc, serde::ts_seconds};
 use serde::Deserialize;
 #[cfg(feature = "serialize")]
 use serde::Serialize;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 use uuid::Uuid;
 
+use crate::helpers::repr_enum;
 use crate::types::Time;
 use crate::types::{Elo, EloChange, MatchId, Rank, Season};
 use crate::user::UserProfile;
+use timeline::TimelineEventKind;
 #[cfg(feature = "variations")]
 use crate::variations::Variation;
 
+pub mod elo_history;
+pub mod live;
+pub mod perspective;
 pub mod requests;
+pub mod splits;
+pub mod stream;
+pub mod sync;
+pub mod timeline;
+pub mod watch;
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 pub mod versus;
 
+/// Category (goal) of a match
+///
+/// Not `Copy`, as [`MatchCategory::Unknown`] owns the value sent by the API
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum MatchCategory {
 	Any,
 	Custom,
@@ -45,6 +58,10 @@ pub enum MatchCategory {
 	AllPortals,
 	AllBlocks,
 	MineAChunk,
+	/// A value not known to this crate, kept as sent by the API
+	#[cfg_attr(not(feature = "strict"), serde(untagged))]
+	#[cfg_attr(feature = "strict", serde(skip))]
+	Unknown(Box<str>),
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -79,13 +96,37 @@ impl MatchSeedInfo {
 	}
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr, Serialize_repr)]
-#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MatchType {
+	Causal,
+	Ranked,
+	Private,
+	Event,
+	/// A value not known to this crate, kept as sent by the API
+	Unknown(u8),
+}
+repr_enum!(query MatchType {
 	Causal = 1,
 	Ranked = 2,
 	Private = 3,
 	Event = 4,
+});
+
+/// Game mode of a match
+///
+/// Not `Copy`, as [`GameMode::Unknown`] owns the value sent by the API
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum GameMode {
+	#[default]
+	Default,
+	/// A value not known to this crate, kept as sent by the API
+	#[cfg_attr(not(feature = "strict"), serde(untagged))]
+	#[cfg_attr(feature = "strict", serde(skip))]
+	Unknown(Box<str>),
 }
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -114,31 +155,46 @@ pub struct MatchEloUpdate {
 	#[serde(rename = "uuid")]
 	pub player_uuid: Uuid,
 	pub change: Option<EloChange>,
+	/// The player's elo after the match, which already includes the `change`
 	#[serde(rename = "eloRate")]
 	pub elo: Option<Elo>,
 }
 
 /// Seed type (overworld)
+///
+/// Not `Copy`, as [`OverworldType::Unknown`] owns the value sent by the API
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum OverworldType {
 	Village,
 	BuriedTreasure,
 	Shipwreck,
 	RuinedPortal,
 	DesertTemple,
+	/// A value not known to this crate, kept as sent by the API
+	#[cfg_attr(not(feature = "strict"), serde(untagged))]
+	#[cfg_attr(feature = "strict", serde(skip))]
+	Unknown(Box<str>),
 }
 
 /// Bastion type
+///
+/// Not `Copy`, as [`BastionType::Unknown`] owns the value sent by the API
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum BastionType {
 	Housing,
 	Treasure,
 	Bridge,
 	Stables,
+	/// A value not known to this crate, kept as sent by the API
+	#[cfg_attr(not(feature = "strict"), serde(untagged))]
+	#[cfg_attr(feature = "strict", serde(skip))]
+	Unknown(Box<str>),
 }
 
 /// Match completion info
@@ -166,6 +222,10 @@ impl MatchTimelineEvent {
 	pub fn id(&self) -> &str {
 		&self.id
 	}
+	/// The kind of the event, parsed from its id
+	pub fn kind(&self) -> TimelineEventKind {
+		TimelineEventKind::from(self.id())
+	}
 }
 
 /// Match info
@@ -189,6 +249,17 @@ pub struct MatchInfo {
 	pub rank: MatchRank,
 	#[serde(rename = "changes")]
 	pub elo_updates: Box<[MatchEloUpdate]>,
+	/// Tag of event and private matches
+	#[serde(default)]
+	pub tag: Option<Box<str>>,
+	#[serde(default)]
+	pub game_mode: GameMode,
+	/// Overworld type of the seed, also found in [`MatchInfo::seed`]
+	#[serde(default)]
+	pub seed_type: Option<OverworldType>,
+	/// Bastion type of the seed, also found in [`MatchInfo::seed`]
+	#[serde(default)]
+	pub bastion_type: Option<BastionType>,
 }
 impl MatchInfo {
 	/// Users participating in the match
@@ -203,13 +274,23 @@ impl MatchInfo {
 	pub fn elo_updates(&self) -> &[MatchEloUpdate] {
 		&self.elo_updates
 	}
+	/// Tag of event and private matches
+	pub fn tag(&self) -> Option<&str> {
+		self.tag.as_deref()
+	}
 	/// Get the overworld type of the match
 	pub fn overworld_type(&self) -> Option<OverworldType> {
-		self.seed.as_ref().and_then(|s| s.overworld)
+		self.seed
+			.as_ref()
+			.and_then(|s| s.overworld.clone())
+			.or_else(|| self.seed_type.clone())
 	}
 	/// Get the bastion type of the match
 	pub fn bastion_type(&self) -> Option<BastionType> {
-		self.seed.as_ref().and_then(|s| s.bastion)
+		self.seed
+			.as_ref()
+			.and_then(|s| s.bastion.clone())
+			.or_else(|| self.bastion_type.clone())
 	}
 }
 
@@ -0,0 +1,153 @@
+use uuid::Uuid;
+
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+use crate::{
+	types::{Elo, EloChange, Time},
+	user::UserProfile,
+};
+
+use super::{AdvancedMatchInfo, MatchCompletion, MatchEloUpdate, MatchInfo};
+
+/// Outcome of a match for a single player
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerOutcome {
+	Win,
+	Loss,
+	/// The match has no winner
+	Draw,
+}
+
+/// View of a match from the perspective of one of its players
+///
+/// Created by [`MatchInfo::perspective`] or [`AdvancedMatchInfo::perspective`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchPerspective<'a> {
+	info: &'a MatchInfo,
+	player: &'a UserProfile,
+	completions: Option<&'a [MatchCompletion]>,
+}
+
+impl<'a> MatchPerspective<'a> {
+	/// The match itself
+	pub fn info(&self) -> &'a MatchInfo {
+		self.info
+	}
+	/// The player whose perspective this is
+	pub fn player(&self) -> &'a UserProfile {
+		self.player
+	}
+	/// The other players of the match
+	pub fn opponents(&self) -> impl Iterator<Item = &'a UserProfile> + 'a {
+		let uuid = self.player.uuid;
+		self.info
+			.players
+			.iter()
+			.filter(move |player| player.uuid != uuid)
+	}
+	/// The opponent of a 1v1 match, the first other player otherwise
+	pub fn opponent(&self) -> Option<&'a UserProfile> {
+		self.opponents().next()
+	}
+
+	/// Whether the player won, lost or the match ended in a draw
+	pub fn outcome(&self) -> PlayerOutcome {
+		match self.info.result.winner_uuid {
+			None => PlayerOutcome::Draw,
+			Some(winner) if winner == self.player.uuid => PlayerOutcome::Win,
+			Some(_) => PlayerOutcome::Loss,
+		}
+	}
+	/// Whether the player won the match
+	pub fn won(&self) -> bool {
+		self.outcome() == PlayerOutcome::Win
+	}
+	/// Whether the player lost the match by forfeiting
+	pub fn forfeited(&self) -> bool {
+		self.info.forfeited && self.outcome() == PlayerOutcome::Loss
+	}
+	/// Whether the opponent lost the match by forfeiting
+	pub fn opponent_forfeited(&self) -> bool {
+		self.info.forfeited && self.won()
+	}
+
+	/// The player's elo update, `None` if the match did not change elo
+	pub fn elo_update(&self) -> Option<&'a MatchEloUpdate> {
+		elo_update(self.info, self.player.uuid)
+	}
+	/// The change to the player's elo
+	pub fn elo_change(&self) -> Option<EloChange> {
+		self.elo_update()?.change
+	}
+	/// The player's elo after the match
+	pub fn elo_after(&self) -> Option<Elo> {
+		self.elo_update()?.elo
+	}
+	/// The player's elo before the match
+	///
+	/// The elo update holds the elo after the match, so this is that elo minus the change
+	pub fn elo_before(&self) -> Option<Elo> {
+		elo_before(self.elo_update()?)
+	}
+	/// The change to the opponent's elo, see [`MatchPerspective::opponent`]
+	pub fn opponent_elo_change(&self) -> Option<EloChange> {
+		elo_update(self.info, self.opponent()?.uuid)?.change
+	}
+	/// The opponent's elo before the match, see [`MatchPerspective::opponent`]
+	pub fn opponent_elo_before(&self) -> Option<Elo> {
+		elo_before(elo_update(self.info, self.opponent()?.uuid)?)
+	}
+
+	/// Time the player needed to complete the match, `None` if they did not complete it
+	///
+	/// Without the completions of an [`AdvancedMatchInfo`],
+	/// only the completion time of a winner who did not win by forfeit is known
+	pub fn completion_time(&self) -> Option<Time> {
+		match self.completions {
+			Some(completions) => completions
+				.iter()
+				.find(|completion| completion.player_uuid == self.player.uuid)
+				.map(|completion| completion.time),
+			None => (self.won() && !self.info.forfeited).then_some(self.info.result.time),
+		}
+	}
+}
+
+fn elo_update(info: &MatchInfo, uuid: Uuid) -> Option<&MatchEloUpdate> {
+	info.elo_updates
+		.iter()
+		.find(|update| update.player_uuid == uuid)
+}
+
+fn elo_before(update: &MatchEloUpdate) -> Option<Elo> {
+	let after = i32::from(update.elo?);
+	Elo::try_from(after - i32::from(update.change?)).ok()
+}
+
+impl MatchInfo {
+	/// View of the match from the perspective of the player with `uuid`,
+	/// `None` if they did not play the match
+	pub fn perspective(&self, uuid: Uuid) -> Option<MatchPerspective<'_>> {
+		let player = self.players.iter().find(|player| player.uuid == uuid)?;
+		Some(MatchPerspective {
+			info: self,
+			player,
+			completions: None,
+		})
+	}
+}
+
+impl AdvancedMatchInfo {
+	/// View of the match from the perspective of the player with `uuid`,
+	/// `None` if they did not play the match
+	///
+	/// Unlike [`MatchInfo::perspective`], the completion time is known for every player
+	pub fn perspective(&self, uuid: Uuid) -> Option<MatchPerspective<'_>> {
+		Some(MatchPerspective {
+			completions: Some(&self.completions),
+			..self.info.perspective(uuid)?
+		})
+	}
+}
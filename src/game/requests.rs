@@ -2,35 +2,53 @@ use serde::Serialize;
 use std::ops::Not;
 
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
 use crate::{
 	Result,
-	helpers::make_request,
+	client::{RankedClient, default_client},
 	pagination::Pagination,
 	types::{MatchId, Season},
 	user::identifier::UserIdentifier,
 };
 
-use super::{AdvancedMatchInfo, MatchInfo, MatchType};
+#[cfg(feature = "blocking")]
+use super::stream::MatchIter;
+use super::{AdvancedMatchInfo, MatchInfo, MatchType, stream::MatchStream};
+
+const BASE_PATH: &str = "matches/{}";
 
-const BASE_URL: &str = "https://api.mcsrranked.com/matches/{}";
+impl RankedClient {
+	/// GET the advanced match info by match `id`
+	pub async fn get_match(&self, id: MatchId) -> Result<AdvancedMatchInfo> {
+		self.request(BASE_PATH, [&id.to_string()], None::<&()>)
+			.await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the advanced match info by match `id`
+	pub fn get_match(&self, id: MatchId) -> Result<AdvancedMatchInfo> {
+		self.request(BASE_PATH, [&id.to_string()], None::<&()>)
+	}
+}
 
 impl AdvancedMatchInfo {
 	pub async fn get_by_id(id: MatchId) -> Result<Self> {
-		make_request(BASE_URL, [&id.to_string()], None::<&()>).await
+		default_client().get_match(id).await
 	}
 }
 
 #[cfg(feature = "blocking")]
 impl AdvancedMatchInfo {
 	pub fn get_by_id_blocking(id: MatchId) -> Result<Self> {
-		make_request_blocking(BASE_URL, [&id.to_string()], None::<&()>)
+		default_blocking_client().get_match(id)
 	}
 }
 
-const USER_URL: &str = "https://api.mcsrranked.com/users/{}/matches";
+const USER_PATH: &str = "users/{}/matches";
 
-/// Parameters for [`UserIdentifier::get_user_matches`]
+/// Parameters for [`UserIdentifier::get_matches`]
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub struct GetMatchesParams {
@@ -50,13 +68,73 @@ impl From<Pagination> for GetMatchesParams {
 	}
 }
 
+impl RankedClient {
+	/// GET the user's matches by `identifier` using given `params`
+	pub async fn get_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<Box<[MatchInfo]>> {
+		self.request(USER_PATH, [&identifier.to_string()], params.into())
+			.await
+	}
+	/// Stream the user's whole match history by `identifier`, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchStream`]
+	pub fn stream_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchStream {
+		MatchStream::new(
+			self.clone(),
+			USER_PATH,
+			Box::new([identifier.to_string()]),
+			params.into().cloned().unwrap_or_default(),
+		)
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the user's matches by `identifier` using given `params`
+	pub fn get_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<Box<[MatchInfo]>> {
+		self.request(USER_PATH, [&identifier.to_string()], params.into())
+	}
+	/// Iterate over the user's whole match history by `identifier`, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchIter`]
+	pub fn iter_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchIter {
+		MatchIter::new(
+			self.clone(),
+			USER_PATH,
+			Box::new([identifier.to_string()]),
+			params.into().cloned().unwrap_or_default(),
+		)
+	}
+}
+
 impl<'a> UserIdentifier<'a> {
 	/// GET the user's matches by identifier using given `params`
 	pub async fn get_matches(
 		&self,
 		params: impl Into<Option<&'a GetMatchesParams>>,
 	) -> Result<Box<[MatchInfo]>> {
-		make_request(USER_URL, [&self.to_string()], params.into()).await
+		default_client().get_user_matches(self, params).await
+	}
+	/// Stream the user's whole match history, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchStream`]
+	pub fn stream_matches(&self, params: impl Into<Option<&'a GetMatchesParams>>) -> MatchStream {
+		default_client().stream_user_matches(self, params)
 	}
 }
 
@@ -67,11 +145,20 @@ impl<'a> UserIdentifier<'a> {
 		&self,
 		params: impl Into<Option<&'a GetMatchesParams>>,
 	) -> Result<Box<[MatchInfo]>> {
-		make_request_blocking(USER_URL, [&self.to_string()], params.into())
+		default_blocking_client().get_user_matches(self, params)
+	}
+	/// Iterate over the user's whole match history, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchIter`]
+	pub fn iter_matches_blocking(
+		&self,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchIter {
+		default_blocking_client().iter_user_matches(self, params)
 	}
 }
 
-const RECENT_URL: &str = "https://api.mcsrranked.com/matches/";
+const RECENT_PATH: &str = "matches";
 
 /// Parameters for [`MatchInfo::get_recent`]
 ///
@@ -137,12 +224,34 @@ impl<'a> GetRecentMatchesParams<'a> {
 	}
 }
 
+impl RankedClient {
+	/// GET recent matches given `params`
+	pub async fn get_recent_matches<'a>(
+		&self,
+		params: impl Into<Option<&'a GetRecentMatchesParams<'a>>>,
+	) -> Result<Box<[MatchInfo]>> {
+		self.request(RECENT_PATH, &[] as &[&str], params.into())
+			.await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET recent matches given `params`
+	pub fn get_recent_matches<'a>(
+		&self,
+		params: impl Into<Option<&'a GetRecentMatchesParams<'a>>>,
+	) -> Result<Box<[MatchInfo]>> {
+		self.request(RECENT_PATH, &[] as &[&str], params.into())
+	}
+}
+
 impl MatchInfo {
 	/// GET recent matches given `params`
 	pub async fn get_recent<'a>(
 		params: impl Into<Option<&'a GetRecentMatchesParams<'a>>>,
 	) -> Result<Box<[Self]>> {
-		make_request(RECENT_URL, &[] as &[&str], params.into()).await
+		default_client().get_recent_matches(params).await
 	}
 }
 
@@ -152,6 +261,6 @@ impl MatchInfo {
 	pub fn get_recent_blocking<'a>(
 		params: impl Into<Option<&'a GetRecentMatchesParams<'a>>>,
 	) -> Result<Box<[Self]>> {
-		make_request_blocking(RECENT_URL, &[] as &[&str], params.into())
+		default_blocking_client().get_recent_matches(params)
 	}
 }
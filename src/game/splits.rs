@@ -0,0 +1,201 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::types::Time;
+
+use super::{AdvancedMatchInfo, timeline::TimelineEventKind};
+
+/// Speedrun milestone of a match, in the usual route order
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Split {
+	EnterNether,
+	FindBastion,
+	FindFortress,
+	/// Throwing an eye of ender from the nether
+	BlindTravel,
+	/// Following an eye of ender to the stronghold
+	Stronghold,
+	EnterEnd,
+	DragonDeath,
+	/// Finishing the match
+	Completion,
+}
+
+impl Split {
+	/// All splits, in route order
+	pub const ALL: [Split; 8] = [
+		Split::EnterNether,
+		Split::FindBastion,
+		Split::FindFortress,
+		Split::BlindTravel,
+		Split::Stronghold,
+		Split::EnterEnd,
+		Split::DragonDeath,
+		Split::Completion,
+	];
+
+	/// The split reached by a timeline event of the given `kind`
+	///
+	/// [`Split::Completion`] is taken from the completions instead of the timeline
+	pub fn from_event(kind: &TimelineEventKind) -> Option<Self> {
+		Some(match kind {
+			TimelineEventKind::StoryEnterTheNether => Split::EnterNether,
+			TimelineEventKind::NetherFindBastion => Split::FindBastion,
+			TimelineEventKind::NetherFindFortress => Split::FindFortress,
+			TimelineEventKind::BlindTravel => Split::BlindTravel,
+			TimelineEventKind::StoryFollowEnderEye => Split::Stronghold,
+			TimelineEventKind::StoryEnterTheEnd => Split::EnterEnd,
+			TimelineEventKind::DragonDeath => Split::DragonDeath,
+			_ => return None,
+		})
+	}
+}
+
+/// Splits of a single player in a match
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerSplits {
+	pub player_uuid: Uuid,
+	/// Time of every split in [`Split::ALL`] order, `None` if it was not reached
+	times: [Option<Time>; 8],
+	/// Amount of times the player reset their world
+	pub resets: u32,
+	/// Time of the last reset, splits reached before it are discarded
+	pub last_reset: Option<Time>,
+}
+
+impl PlayerSplits {
+	fn new(player_uuid: Uuid) -> Self {
+		Self {
+			player_uuid,
+			times: [None; 8],
+			resets: 0,
+			last_reset: None,
+		}
+	}
+
+	fn reset(&mut self, time: Time) {
+		self.times = [None; 8];
+		self.resets += 1;
+		self.last_reset = Some(time);
+	}
+
+	fn reach(&mut self, split: Split, time: Time) {
+		// Only the first time a split is reached counts
+		self.times[split as usize].get_or_insert(time);
+	}
+
+	/// Match time at which the `split` was reached, `None` if it was missed
+	pub fn time(&self, split: Split) -> Option<Time> {
+		self.times[split as usize]
+	}
+
+	/// Duration of the `split`, since the previous split reached
+	///
+	/// The first split is measured from the last reset before it, or from the start of the match.
+	/// `None` if the split was missed
+	pub fn duration(&self, split: Split) -> Option<Time> {
+		let time = self.time(split)?;
+		let previous = self
+			.splits()
+			.filter_map(|(other, other_time)| Some((other_time?, other)))
+			.filter(|&reached| reached < (time, split))
+			.map(|(other_time, _)| other_time)
+			.chain(self.last_reset.filter(|&reset| reset <= time))
+			.max()
+			.unwrap_or(Time::new(0));
+		Some(Time::new(time.0.saturating_sub(previous.0)))
+	}
+
+	/// All splits with their times, in route order
+	pub fn splits(&self) -> impl Iterator<Item = (Split, Option<Time>)> + '_ {
+		Split::ALL
+			.into_iter()
+			.map(|split| (split, self.time(split)))
+	}
+
+	/// Splits which were not reached, in route order
+	pub fn missing(&self) -> impl Iterator<Item = Split> + '_ {
+		self.splits()
+			.filter_map(|(split, time)| time.is_none().then_some(split))
+	}
+
+	/// Whether the player finished the match
+	pub fn completed(&self) -> bool {
+		self.time(Split::Completion).is_some()
+	}
+}
+
+/// Split tables of all players of a match
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchSplits {
+	/// One entry per player, in the order of [`super::MatchInfo::players`]
+	pub players: Box<[PlayerSplits]>,
+}
+
+impl MatchSplits {
+	/// Splits of the player with `uuid`, `None` if they did not play the match
+	pub fn player(&self, uuid: Uuid) -> Option<&PlayerSplits> {
+		self.players
+			.iter()
+			.find(|splits| splits.player_uuid == uuid)
+	}
+	/// Fastest time of the `split` among all players
+	pub fn best(&self, split: Split) -> Option<(Uuid, Time)> {
+		self.players
+			.iter()
+			.filter_map(|splits| Some((splits.player_uuid, splits.time(split)?)))
+			.min_by_key(|&(_, time)| time)
+	}
+}
+
+impl From<&AdvancedMatchInfo> for MatchSplits {
+	fn from(info: &AdvancedMatchInfo) -> Self {
+		let mut players: Vec<PlayerSplits> = info
+			.info
+			.players
+			.iter()
+			.map(|player| PlayerSplits::new(player.uuid))
+			.collect();
+
+		// The timeline is sent newest first
+		let mut events: Vec<_> = info.timeline_events.iter().collect();
+		events.sort_by_key(|event| event.time);
+		for event in events {
+			let Some(splits) = players
+				.iter_mut()
+				.find(|splits| splits.player_uuid == event.player_uuid)
+			else {
+				continue;
+			};
+			let kind = event.kind();
+			match Split::from_event(&kind) {
+				Some(split) => splits.reach(split, event.time),
+				None if kind == TimelineEventKind::Reset => splits.reset(event.time),
+				None => {}
+			}
+		}
+		for completion in &info.completions {
+			if let Some(splits) = players
+				.iter_mut()
+				.find(|splits| splits.player_uuid == completion.player_uuid)
+			{
+				splits.reach(Split::Completion, completion.time);
+			}
+		}
+
+		Self {
+			players: players.into(),
+		}
+	}
+}
+
+impl AdvancedMatchInfo {
+	/// Split tables of all players, see [`MatchSplits`]
+	pub fn splits(&self) -> MatchSplits {
+		MatchSplits::from(self)
+	}
+}
@@ -0,0 +1,189 @@
+use std::{
+	collections::VecDeque,
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use futures_util::{Stream, ready};
+
+#[cfg(feature = "blocking")]
+use crate::client::blocking::BlockingRankedClient;
+use crate::{Result, client::RankedClient};
+
+use super::{MatchInfo, requests::GetMatchesParams};
+
+type PageFuture = Pin<Box<dyn Future<Output = Result<Box<[MatchInfo]>>> + Send>>;
+
+/// Walks back through pages of matches using the id of the last match seen
+#[derive(Debug, Clone)]
+struct Pager {
+	path: &'static str,
+	variables: Box<[String]>,
+	params: GetMatchesParams,
+	done: bool,
+}
+
+impl Pager {
+	fn new(path: &'static str, variables: Box<[String]>, params: GetMatchesParams) -> Self {
+		Self {
+			path,
+			variables,
+			params,
+			done: false,
+		}
+	}
+
+	/// Parameters of the next page, `None` once all pages were fetched
+	fn next_params(&self) -> Option<GetMatchesParams> {
+		(!self.done).then(|| self.params.clone())
+	}
+
+	/// Move past the fetched `page`
+	fn advance(&mut self, page: &[MatchInfo]) {
+		match page.last() {
+			// A page shorter than requested is the last one
+			Some(last) if page.len() >= usize::from(self.params.pagination.count.get()) => {
+				self.params.pagination.position.before = Some(last.id);
+			}
+			_ => self.done = true,
+		}
+	}
+}
+
+/// Stream of matches paging through a whole match history, newest first
+///
+/// Created by [`RankedClient::stream_user_matches`] and [`RankedClient::stream_versus_matches`].
+/// Pages are fetched lazily with the count and filters of the given [`GetMatchesParams`],
+/// each page starting before the last match of the previous one.
+/// The stream ends after the last page or after yielding an error.
+pub struct MatchStream {
+	client: RankedClient,
+	pager: Pager,
+	buffer: VecDeque<MatchInfo>,
+	pending: Option<PageFuture>,
+}
+
+impl MatchStream {
+	pub(crate) fn new(
+		client: RankedClient,
+		path: &'static str,
+		variables: Box<[String]>,
+		params: GetMatchesParams,
+	) -> Self {
+		Self {
+			client,
+			pager: Pager::new(path, variables, params),
+			buffer: VecDeque::new(),
+			pending: None,
+		}
+	}
+}
+
+impl Stream for MatchStream {
+	type Item = Result<MatchInfo>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		loop {
+			if let Some(info) = this.buffer.pop_front() {
+				return Poll::Ready(Some(Ok(info)));
+			}
+			let pending = match &mut this.pending {
+				Some(pending) => pending,
+				None => {
+					let Some(params) = this.pager.next_params() else {
+						return Poll::Ready(None);
+					};
+					let client = this.client.clone();
+					let path = this.pager.path;
+					let variables = this.pager.variables.clone();
+					this.pending.insert(Box::pin(async move {
+						client.request(path, variables.iter(), Some(&params)).await
+					}))
+				}
+			};
+			let result = ready!(pending.as_mut().poll(cx));
+			this.pending = None;
+			match result {
+				Ok(page) => {
+					this.pager.advance(&page);
+					this.buffer.extend(page);
+				}
+				Err(err) => {
+					this.pager.done = true;
+					return Poll::Ready(Some(Err(err)));
+				}
+			}
+		}
+	}
+}
+
+impl std::fmt::Debug for MatchStream {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MatchStream")
+			.field("client", &self.client)
+			.field("pager", &self.pager)
+			.field("buffered", &self.buffer.len())
+			.field("pending", &self.pending.is_some())
+			.finish()
+	}
+}
+
+/// Iterator over matches paging through a whole match history, newest first
+///
+/// Blocking counterpart of [`MatchStream`],
+/// created by [`BlockingRankedClient::iter_user_matches`]
+/// and [`BlockingRankedClient::iter_versus_matches`]
+#[cfg(feature = "blocking")]
+#[derive(Debug)]
+pub struct MatchIter {
+	client: BlockingRankedClient,
+	pager: Pager,
+	buffer: VecDeque<MatchInfo>,
+}
+
+#[cfg(feature = "blocking")]
+impl MatchIter {
+	pub(crate) fn new(
+		client: BlockingRankedClient,
+		path: &'static str,
+		variables: Box<[String]>,
+		params: GetMatchesParams,
+	) -> Self {
+		Self {
+			client,
+			pager: Pager::new(path, variables, params),
+			buffer: VecDeque::new(),
+		}
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for MatchIter {
+	type Item = Result<MatchInfo>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(info) = self.buffer.pop_front() {
+				return Some(Ok(info));
+			}
+			let params = self.pager.next_params()?;
+			let result = self.client.request::<Box<[MatchInfo]>, _, _>(
+				self.pager.path,
+				self.pager.variables.iter(),
+				Some(&params),
+			);
+			match result {
+				Ok(page) => {
+					self.pager.advance(&page);
+					self.buffer.extend(page);
+				}
+				Err(err) => {
+					self.pager.done = true;
+					return Some(Err(err));
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,279 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs, io,
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+use futures_util::TryStreamExt;
+use uuid::Uuid;
+
+#[cfg(feature = "blocking")]
+use crate::client::blocking::BlockingRankedClient;
+use crate::{
+	Result, client::RankedClient, pagination::RelativePos, types::MatchId,
+	user::identifier::UserIdentifier,
+};
+
+use super::{MatchInfo, requests::GetMatchesParams};
+
+/// Storage of the last synced [`MatchId`] per key
+///
+/// The key of [`RankedClient::sync_user_matches`] is the hyphenated UUID of the user,
+/// followed by the `type`, `season` and `exclude_decay` filters if they are set,
+/// such as `79635c3d-bf63-4a22-8bf4-4544cc7c0d27?type=2&season=5`
+pub trait CheckpointStore {
+	/// The checkpoint stored under `key`, `None` if nothing was synced yet
+	fn load(&self, key: &str) -> io::Result<Option<MatchId>>;
+	/// Store the `checkpoint` under `key`
+	fn save(&self, key: &str, checkpoint: MatchId) -> io::Result<()>;
+}
+
+impl<S: CheckpointStore + ?Sized> CheckpointStore for &S {
+	fn load(&self, key: &str) -> io::Result<Option<MatchId>> {
+		(**self).load(key)
+	}
+	fn save(&self, key: &str, checkpoint: MatchId) -> io::Result<()> {
+		(**self).save(key, checkpoint)
+	}
+}
+
+/// Checkpoint store keeping all checkpoints in a single JSON file
+///
+/// The file is created on the first save and replaced atomically on every save
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+	path: PathBuf,
+	lock: Mutex<()>,
+}
+
+impl FileCheckpointStore {
+	/// Construct a store backed by the file at `path`
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: path.into(),
+			lock: Mutex::new(()),
+		}
+	}
+	/// Path of the checkpoint file
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	fn read(&self) -> io::Result<BTreeMap<String, MatchId>> {
+		match fs::read(&self.path) {
+			Ok(data) => Ok(serde_json::from_slice(&data)?),
+			Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+			Err(err) => Err(err),
+		}
+	}
+}
+
+impl CheckpointStore for FileCheckpointStore {
+	fn load(&self, key: &str) -> io::Result<Option<MatchId>> {
+		let _lock = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+		Ok(self.read()?.get(key).copied())
+	}
+	fn save(&self, key: &str, checkpoint: MatchId) -> io::Result<()> {
+		let _lock = self.lock.lock().unwrap_or_else(|err| err.into_inner());
+		let mut checkpoints = self.read()?;
+		checkpoints.insert(key.to_owned(), checkpoint);
+
+		if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+			fs::create_dir_all(dir)?;
+		}
+		let temp = self.path.with_extension("tmp");
+		fs::write(&temp, serde_json::to_vec_pretty(&checkpoints)?)?;
+		fs::rename(&temp, &self.path)
+	}
+}
+
+/// Checkpoint store kept in memory, mostly useful for tests
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+	checkpoints: Mutex<HashMap<String, MatchId>>,
+}
+
+impl MemoryCheckpointStore {
+	/// Construct an empty store
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+	fn load(&self, key: &str) -> io::Result<Option<MatchId>> {
+		let checkpoints = self
+			.checkpoints
+			.lock()
+			.unwrap_or_else(|err| err.into_inner());
+		Ok(checkpoints.get(key).copied())
+	}
+	fn save(&self, key: &str, checkpoint: MatchId) -> io::Result<()> {
+		let mut checkpoints = self
+			.checkpoints
+			.lock()
+			.unwrap_or_else(|err| err.into_inner());
+		checkpoints.insert(key.to_owned(), checkpoint);
+		Ok(())
+	}
+}
+
+/// Result of an incremental match sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchSync {
+	/// Matches newer than the previous checkpoint, newest first
+	pub matches: Vec<MatchInfo>,
+	/// Id of the newest synced match, the previous checkpoint if nothing new was found
+	pub checkpoint: Option<MatchId>,
+}
+
+impl MatchSync {
+	fn new(matches: Vec<MatchInfo>, previous: Option<MatchId>) -> Self {
+		let checkpoint = matches.iter().map(|info| info.id).chain(previous).max();
+		Self {
+			matches,
+			checkpoint,
+		}
+	}
+}
+
+/// Checkpoint key of the user with `uuid` synced with the filters of `params`
+///
+/// The pagination is left out, as it does not change which matches are synced
+pub(super) fn checkpoint_key(uuid: Uuid, params: Option<&GetMatchesParams>) -> String {
+	let mut filters = Vec::new();
+	if let Some(params) = params {
+		if let Some(kind) = params.kind {
+			filters.push(format!("type={}", kind.value()));
+		}
+		if let Some(season) = params.season {
+			filters.push(format!("season={season}"));
+		}
+		if params.exclude_decay {
+			filters.push("exclude_decay=true".to_owned());
+		}
+	}
+	let uuid = uuid.hyphenated().to_string();
+	match filters.is_empty() {
+		true => uuid,
+		false => format!("{uuid}?{}", filters.join("&")),
+	}
+}
+
+/// Parameters fetching the matches after the `checkpoint`, keeping the filters of `params`
+fn params_after(
+	params: Option<&GetMatchesParams>,
+	checkpoint: Option<MatchId>,
+) -> GetMatchesParams {
+	let mut params = params.cloned().unwrap_or_default();
+	params.pagination.position = match checkpoint {
+		Some(checkpoint) => RelativePos::after(checkpoint),
+		None => RelativePos::default(),
+	};
+	params
+}
+
+impl RankedClient {
+	/// GET all of the user's matches newer than the `checkpoint`
+	///
+	/// Pages through the matches after the checkpoint, see [`super::stream::MatchStream`],
+	/// the whole history is fetched if `checkpoint` is `None`.
+	/// The pages are requested without the client's response cache
+	pub async fn get_user_matches_after<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		checkpoint: Option<MatchId>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<MatchSync> {
+		let params = params_after(params.into(), checkpoint);
+		let matches = self
+			.without_cache()
+			.stream_user_matches(identifier, &params)
+			.try_collect()
+			.await?;
+		Ok(MatchSync::new(matches, checkpoint))
+	}
+
+	/// Sync the user's matches newer than the checkpoint in the `store`
+	///
+	/// The checkpoint is stored under the user's UUID and the filters of `params`, see [`CheckpointStore`],
+	/// so the user is looked up first unless `identifier` is a UUID.
+	/// It is only updated once all new matches were fetched, so a failed sync can simply be repeated
+	pub async fn sync_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+		store: &impl CheckpointStore,
+	) -> Result<MatchSync> {
+		let params = params.into();
+		let uuid = match identifier {
+			UserIdentifier::Uuid(uuid) => *uuid,
+			_ => self.get_user(identifier, None).await?.profile.uuid,
+		};
+		let key = checkpoint_key(uuid, params);
+		let previous = store.load(&key)?;
+		let sync = self
+			.get_user_matches_after(identifier, previous, params)
+			.await?;
+		save_checkpoint(store, &key, previous, &sync)?;
+		Ok(sync)
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET all of the user's matches newer than the `checkpoint`
+	///
+	/// Pages through the matches after the checkpoint, see [`super::stream::MatchIter`],
+	/// the whole history is fetched if `checkpoint` is `None`.
+	/// The pages are requested without the client's response cache
+	pub fn get_user_matches_after<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		checkpoint: Option<MatchId>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<MatchSync> {
+		let params = params_after(params.into(), checkpoint);
+		let matches = self
+			.without_cache()
+			.iter_user_matches(identifier, &params)
+			.collect::<Result<_>>()?;
+		Ok(MatchSync::new(matches, checkpoint))
+	}
+
+	/// Synchronously sync the user's matches newer than the checkpoint in the `store`
+	///
+	/// The checkpoint is stored under the user's UUID and the filters of `params`, see [`CheckpointStore`],
+	/// so the user is looked up first unless `identifier` is a UUID.
+	/// It is only updated once all new matches were fetched, so a failed sync can simply be repeated
+	pub fn sync_user_matches<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+		store: &impl CheckpointStore,
+	) -> Result<MatchSync> {
+		let params = params.into();
+		let uuid = match identifier {
+			UserIdentifier::Uuid(uuid) => *uuid,
+			_ => self.get_user(identifier, None)?.profile.uuid,
+		};
+		let key = checkpoint_key(uuid, params);
+		let previous = store.load(&key)?;
+		let sync = self.get_user_matches_after(identifier, previous, params)?;
+		save_checkpoint(store, &key, previous, &sync)?;
+		Ok(sync)
+	}
+}
+
+fn save_checkpoint(
+	store: &impl CheckpointStore,
+	key: &str,
+	previous: Option<MatchId>,
+	sync: &MatchSync,
+) -> io::Result<()> {
+	match sync.checkpoint {
+		Some(checkpoint) if sync.checkpoint != previous => store.save(key, checkpoint),
+		_ => Ok(()),
+	}
+}
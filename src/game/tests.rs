@@ -3,18 +3,20 @@ use uuid::uuid;
 
 use crate::{
 	game::{
-		AdvancedMatchInfo, BastionType, MatchCategory, MatchCompletion, MatchEloUpdate,
+		AdvancedMatchInfo, BastionType, GameMode, MatchCategory, MatchCompletion, MatchEloUpdate,
 		MatchOutcome, MatchRank, MatchSeedInfo, MatchTimelineEvent, MatchType, OverworldType,
 	},
 	types::Time,
 	user::{SupporterTier, UserProfile},
 };
 
+/// Match 1524115 as returned by the API
+pub(crate) const MATCH_1524115: &str = r#"{"id":1524115,"type":2,"seed":{"id":null,"overworld":"VILLAGE","nether":"HOUSING","variations":[]},"category":"ANY","gameMode":"default","players":[{"uuid":"79635c3dbf634a228bf44544cc7c0d27","nickname":"LaysarOwO","roleType":0,"eloRate":1146,"eloRank":1048,"country":null},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","nickname":"Frigbob","roleType":0,"eloRate":1470,"eloRank":211,"country":null}],"spectators":[],"result":{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":620128},"forfeited":false,"decayed":false,"rank":{"season":1000,"allTime":null},"changes":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","change":19,"eloRate":1282},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","change":-19,"eloRate":1245}],"completions":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":620128}],"timelines":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":610048,"type":"projectelo.timeline.dragon_death"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":535511,"type":"end.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":535393,"type":"story.enter_the_end"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":494792,"type":"story.follow_ender_eye"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":454844,"type":"story.follow_ender_eye"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":419050,"type":"projectelo.timeline.blind_travel"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":358761,"type":"projectelo.timeline.blind_travel"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":354196,"type":"nether.obtain_blaze_rod"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":331692,"type":"nether.obtain_blaze_rod"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":320335,"type":"nether.find_fortress"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":311082,"type":"adventure.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":310966,"type":"adventure.kill_a_mob"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":310597,"type":"nether.find_fortress"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":290740,"type":"husbandry.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":282496,"type":"adventure.kill_a_mob"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":282404,"type":"adventure.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":266018,"type":"nether.loot_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":255043,"type":"story.form_obsidian"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":235241,"type":"nether.obtain_crying_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":230341,"type":"nether.obtain_crying_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":223692,"type":"story.form_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":170186,"type":"nether.distract_piglin"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":162694,"type":"nether.loot_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":161055,"type":"nether.find_bastion"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":157338,"type":"nether.find_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":153691,"type":"husbandry.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":132943,"type":"story.enter_the_nether"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":132837,"type":"nether.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":120786,"type":"story.lava_bucket"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":118642,"type":"nether.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":118522,"type":"story.enter_the_nether"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":106937,"type":"story.lava_bucket"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":37230,"type":"story.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":33038,"type":"story.mine_stone"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":31439,"type":"story.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29794,"type":"story.obtain_armor"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29794,"type":"story.iron_tools"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29684,"type":"story.smelt_iron"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":24938,"type":"story.mine_stone"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":23237,"type":"story.iron_tools"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":22337,"type":"story.obtain_armor"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":21037,"type":"story.smelt_iron"}],"season":7,"date":1735326765,"seedType":"VILLAGE","bastionType":"HOUSING","tag":null,"replayExist":false}"#;
+
 #[test]
 fn match_info() {
-	const JSON: &str = r#"{"id":1524115,"type":2,"seed":{"id":null,"overworld":"VILLAGE","nether":"HOUSING","variations":[]},"category":"ANY","gameMode":"default","players":[{"uuid":"79635c3dbf634a228bf44544cc7c0d27","nickname":"LaysarOwO","roleType":0,"eloRate":1146,"eloRank":1048,"country":null},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","nickname":"Frigbob","roleType":0,"eloRate":1470,"eloRank":211,"country":null}],"spectators":[],"result":{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":620128},"forfeited":false,"decayed":false,"rank":{"season":1000,"allTime":null},"changes":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","change":19,"eloRate":1282},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","change":-19,"eloRate":1245}],"completions":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":620128}],"timelines":[{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":610048,"type":"projectelo.timeline.dragon_death"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":535511,"type":"end.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":535393,"type":"story.enter_the_end"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":494792,"type":"story.follow_ender_eye"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":454844,"type":"story.follow_ender_eye"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":419050,"type":"projectelo.timeline.blind_travel"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":358761,"type":"projectelo.timeline.blind_travel"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":354196,"type":"nether.obtain_blaze_rod"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":331692,"type":"nether.obtain_blaze_rod"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":320335,"type":"nether.find_fortress"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":311082,"type":"adventure.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":310966,"type":"adventure.kill_a_mob"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":310597,"type":"nether.find_fortress"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":290740,"type":"husbandry.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":282496,"type":"adventure.kill_a_mob"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":282404,"type":"adventure.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":266018,"type":"nether.loot_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":255043,"type":"story.form_obsidian"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":235241,"type":"nether.obtain_crying_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":230341,"type":"nether.obtain_crying_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":223692,"type":"story.form_obsidian"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":170186,"type":"nether.distract_piglin"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":162694,"type":"nether.loot_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":161055,"type":"nether.find_bastion"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":157338,"type":"nether.find_bastion"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":153691,"type":"husbandry.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":132943,"type":"story.enter_the_nether"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":132837,"type":"nether.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":120786,"type":"story.lava_bucket"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":118642,"type":"nether.root"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":118522,"type":"story.enter_the_nether"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":106937,"type":"story.lava_bucket"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":37230,"type":"story.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":33038,"type":"story.mine_stone"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":31439,"type":"story.root"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29794,"type":"story.obtain_armor"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29794,"type":"story.iron_tools"},{"uuid":"79635c3dbf634a228bf44544cc7c0d27","time":29684,"type":"story.smelt_iron"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":24938,"type":"story.mine_stone"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":23237,"type":"story.iron_tools"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":22337,"type":"story.obtain_armor"},{"uuid":"7d3a6bb9f62645ae80cf40840ca84c50","time":21037,"type":"story.smelt_iron"}],"season":7,"date":1735326765,"seedType":"VILLAGE","bastionType":"HOUSING","tag":null,"replayExist":false}"#;
 
-	let full_info: AdvancedMatchInfo = serde_json::from_str(JSON).unwrap();
+	let full_info: AdvancedMatchInfo = serde_json::from_str(MATCH_1524115).unwrap();
 	let info = &full_info.info;
 	assert_eq!(info.id, 1524115);
 	assert_eq!(info.kind, MatchType::Ranked);
@@ -59,6 +61,10 @@ fn match_info() {
 	);
 	assert_eq!(info.overworld_type(), Some(OverworldType::Village));
 	assert_eq!(info.bastion_type(), Some(BastionType::Housing));
+	assert_eq!(info.tag(), None);
+	assert_eq!(info.game_mode, GameMode::Default);
+	assert_eq!(info.seed_type, Some(OverworldType::Village));
+	assert_eq!(info.bastion_type, Some(BastionType::Housing));
 	assert_eq!(
 		info.result,
 		MatchOutcome {
@@ -321,6 +327,74 @@ fn match_info() {
 	}
 }
 
+#[test]
+fn match_tag_and_game_mode() {
+	use super::MatchInfo;
+
+	let mut data: serde_json::Value = serde_json::from_str(MATCH_1524115).unwrap();
+	data["type"] = 4.into();
+	data["tag"] = "scrims-week-3".into();
+	let info: MatchInfo = serde_json::from_value(data.clone()).unwrap();
+	assert_eq!(info.kind, MatchType::Event);
+	assert_eq!(info.tag(), Some("scrims-week-3"));
+	assert_eq!(info.game_mode, GameMode::Default);
+
+	data["gameMode"] = "ranked_hardcore".into();
+	#[cfg(feature = "strict")]
+	{
+		assert!(serde_json::from_value::<MatchInfo>(data.clone()).is_err());
+		let tagged = serde_json::json!({ "unknown": "ranked_hardcore" });
+		assert!(serde_json::from_value::<GameMode>(tagged).is_err());
+	}
+	#[cfg(not(feature = "strict"))]
+	{
+		let info: MatchInfo = serde_json::from_value(data.clone()).unwrap();
+		assert_eq!(info.game_mode, GameMode::Unknown("ranked_hardcore".into()));
+	}
+
+	let object = data.as_object_mut().unwrap();
+	for key in ["tag", "gameMode", "seedType", "bastionType"] {
+		object.remove(key);
+	}
+	let info: MatchInfo = serde_json::from_value(data).unwrap();
+	assert_eq!(info.tag(), None);
+	assert_eq!(info.game_mode, GameMode::Default);
+	assert_eq!(info.bastion_type(), Some(BastionType::Housing));
+
+	#[cfg(feature = "serialize")]
+	{
+		#[cfg(not(feature = "strict"))]
+		{
+			let json = serde_json::to_value(GameMode::Unknown("ranked_hardcore".into())).unwrap();
+			assert_eq!(json, "ranked_hardcore");
+		}
+		assert_eq!(serde_json::to_value(GameMode::Default).unwrap(), "default");
+	}
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn match_info_round_trip() {
+	use super::MatchInfo;
+
+	let mut data: serde_json::Value = serde_json::from_str(MATCH_1524115).unwrap();
+	// Unknown game modes are rejected under the `strict` feature
+	let game_mode = if cfg!(feature = "strict") {
+		"default"
+	} else {
+		"ranked_hardcore"
+	};
+	data["tag"] = "scrims-week-3".into();
+	data["gameMode"] = game_mode.into();
+	let info: MatchInfo = serde_json::from_value(data).unwrap();
+
+	let json = serde_json::to_value(&info).unwrap();
+	assert_eq!(json["tag"], "scrims-week-3");
+	assert_eq!(json["gameMode"], game_mode);
+	let round_trip: MatchInfo = serde_json::from_value(json).unwrap();
+	assert_eq!(round_trip, info);
+}
+
 #[test]
 fn match_category() {
 	assert_eq!(
@@ -419,6 +493,23 @@ fn match_category() {
 		serde_json::from_str::<MatchCategory>(r#""MINE_A_CHUNK""#).unwrap(),
 		MatchCategory::MineAChunk
 	);
+
+	#[cfg(feature = "strict")]
+	{
+		assert!(serde_json::from_str::<MatchCategory>(r#""NEW_CATEGORY""#).is_err());
+		let tagged = r#"{"UNKNOWN":"NEW_CATEGORY"}"#;
+		assert!(serde_json::from_str::<MatchCategory>(tagged).is_err());
+	}
+	#[cfg(not(feature = "strict"))]
+	{
+		let unknown = serde_json::from_str::<MatchCategory>(r#""NEW_CATEGORY""#).unwrap();
+		assert_eq!(unknown, MatchCategory::Unknown("NEW_CATEGORY".into()));
+		#[cfg(feature = "serialize")]
+		assert_eq!(
+			serde_json::to_string(&unknown).unwrap(),
+			r#""NEW_CATEGORY""#
+		);
+	}
 }
 
 #[test]
@@ -439,8 +530,17 @@ fn match_type() {
 		serde_json::from_str::<MatchType>("4").unwrap(),
 		MatchType::Event
 	);
-	assert!(serde_json::from_str::<MatchType>("0").is_err());
+	assert_eq!(MatchType::Event.value(), 4);
+
+	#[cfg(feature = "strict")]
 	assert!(serde_json::from_str::<MatchType>("99").is_err());
+	#[cfg(not(feature = "strict"))]
+	{
+		let unknown = serde_json::from_str::<MatchType>("99").unwrap();
+		assert_eq!(unknown, MatchType::Unknown(99));
+		assert_eq!(serde_json::to_string(&unknown).unwrap(), "99");
+	}
+	assert!(serde_json::from_str::<MatchType>("-1").is_err());
 }
 
 #[test]
@@ -465,7 +565,13 @@ fn overworld_type() {
 		serde_json::from_str::<OverworldType>(r#""RUINED_PORTAL""#).unwrap(),
 		OverworldType::RuinedPortal
 	);
+	#[cfg(feature = "strict")]
 	assert!(serde_json::from_str::<OverworldType>(r#""DOESN'T EXIST""#).is_err());
+	#[cfg(not(feature = "strict"))]
+	assert_eq!(
+		serde_json::from_str::<OverworldType>(r#""DOESN'T EXIST""#).unwrap(),
+		OverworldType::Unknown("DOESN'T EXIST".into())
+	);
 }
 
 #[test]
@@ -486,7 +592,17 @@ fn bastion_type() {
 		serde_json::from_str::<BastionType>(r#""STABLES""#).unwrap(),
 		BastionType::Stables
 	);
-	assert!(serde_json::from_str::<BastionType>(r#""DOESN'T EXIST""#).is_err());
+	#[cfg(feature = "strict")]
+	{
+		assert!(serde_json::from_str::<BastionType>(r#""DOESN'T EXIST""#).is_err());
+		let tagged = r#"{"UNKNOWN":"DOESN'T EXIST"}"#;
+		assert!(serde_json::from_str::<BastionType>(tagged).is_err());
+	}
+	#[cfg(not(feature = "strict"))]
+	assert_eq!(
+		serde_json::from_str::<BastionType>(r#""DOESN'T EXIST""#).unwrap(),
+		BastionType::Unknown("DOESN'T EXIST".into())
+	);
 
 	#[cfg(feature = "serialize")]
 	{
@@ -508,3 +624,748 @@ fn bastion_type() {
 		);
 	}
 }
+
+pub(in crate::game) mod stream {
+	use futures_util::{StreamExt, TryStreamExt};
+	use serde_json::{Value, json};
+
+	use crate::{
+		client::{MockTransport, RankedClient},
+		game::{MatchType, requests::GetMatchesParams},
+		pagination::Pagination,
+		types::MatchId,
+		user::identifier::UserIdentifier,
+	};
+
+	const USER_URL: &str = "https://api.mcsrranked.com/users/LaysarOwO/matches";
+
+	pub(in crate::game) fn match_json(id: MatchId) -> Value {
+		json!({
+			"id": id,
+			"type": 2,
+			"season": 7,
+			"category": "ANY",
+			"date": 1735326765,
+			"players": [],
+			"spectators": [],
+			"seed": null,
+			"result": { "uuid": null, "time": 0 },
+			"forfeited": false,
+			"decayed": false,
+			"rank": { "season": null, "allTime": null },
+			"changes": []
+		})
+	}
+
+	pub(in crate::game) fn page(ids: &[MatchId]) -> Value {
+		ids.iter().copied().map(match_json).collect()
+	}
+
+	fn mock() -> MockTransport {
+		MockTransport::new()
+			.with_data(
+				format!("{USER_URL}?count=2&type=2&exclude_decay=false"),
+				page(&[5, 4]),
+			)
+			.with_data(
+				format!("{USER_URL}?count=2&before=4&type=2&exclude_decay=false"),
+				page(&[3, 2]),
+			)
+			.with_data(
+				format!("{USER_URL}?count=2&before=2&type=2&exclude_decay=false"),
+				page(&[1]),
+			)
+	}
+
+	fn params() -> GetMatchesParams {
+		GetMatchesParams {
+			kind: Some(MatchType::Ranked),
+			..Pagination::count(2).unwrap().into()
+		}
+	}
+
+	#[tokio::test]
+	async fn match_stream() {
+		let mock = mock();
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.build()
+			.unwrap();
+		let ids: Vec<MatchId> = client
+			.stream_user_matches(&UserIdentifier::Nickname("LaysarOwO"), &params())
+			.map_ok(|info| info.id)
+			.try_collect()
+			.await
+			.unwrap();
+		assert_eq!(ids, [5, 4, 3, 2, 1]);
+		assert_eq!(mock.requests().len(), 3);
+	}
+
+	#[tokio::test]
+	async fn match_stream_error() {
+		let mock = MockTransport::new();
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.build()
+			.unwrap();
+		let results: Vec<_> = client
+			.stream_user_matches(&UserIdentifier::Nickname("LaysarOwO"), None)
+			.collect()
+			.await;
+		assert_eq!(results.len(), 1);
+		assert!(results[0].is_err());
+	}
+
+	#[cfg(feature = "blocking")]
+	#[test]
+	fn match_iter() {
+		use crate::client::blocking::BlockingRankedClient;
+
+		let client = BlockingRankedClient::builder()
+			.no_retry()
+			.transport(mock())
+			.build()
+			.unwrap();
+		let ids = client
+			.iter_user_matches(&UserIdentifier::Nickname("LaysarOwO"), &params())
+			.map(|info| info.map(|info| info.id))
+			.collect::<crate::Result<Vec<_>>>()
+			.unwrap();
+		assert_eq!(ids, [5, 4, 3, 2, 1]);
+	}
+}
+
+mod sync {
+	use serde_json::Value;
+	use uuid::{Uuid, uuid};
+
+	use crate::{
+		client::{MockTransport, RankedClient, ResponseCache},
+		game::{
+			MatchType,
+			requests::GetMatchesParams,
+			sync::{CheckpointStore, FileCheckpointStore, MemoryCheckpointStore, checkpoint_key},
+		},
+		pagination::Pagination,
+		user::identifier::UserIdentifier,
+	};
+
+	use super::stream::page;
+
+	const LAYSAR: Uuid = uuid!("79635c3dbf634a228bf44544cc7c0d27");
+	const KEY: &str = "79635c3d-bf63-4a22-8bf4-4544cc7c0d27";
+	const USER_URL: &str =
+		"https://api.mcsrranked.com/users/79635c3d-bf63-4a22-8bf4-4544cc7c0d27/matches";
+
+	fn client(mock: &MockTransport) -> RankedClient {
+		RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.build()
+			.unwrap()
+	}
+
+	#[test]
+	fn keys() {
+		assert_eq!(checkpoint_key(LAYSAR, None), KEY);
+		let paginated = GetMatchesParams::from(Pagination::count(2).unwrap());
+		assert_eq!(checkpoint_key(LAYSAR, Some(&paginated)), KEY);
+
+		let filtered = GetMatchesParams {
+			kind: Some(MatchType::Ranked),
+			season: Some(5),
+			exclude_decay: true,
+			..Default::default()
+		};
+		assert_eq!(
+			checkpoint_key(LAYSAR, Some(&filtered)),
+			format!("{KEY}?type=2&season=5&exclude_decay=true")
+		);
+	}
+
+	#[tokio::test]
+	async fn sync_user_matches() {
+		let mock = MockTransport::new()
+			.with_data(
+				format!("{USER_URL}?count=2&after=3&exclude_decay=false"),
+				page(&[7, 6]),
+			)
+			.with_data(
+				format!("{USER_URL}?count=2&before=6&after=3&exclude_decay=false"),
+				page(&[5, 4]),
+			)
+			.with_data(
+				format!("{USER_URL}?count=2&before=4&after=3&exclude_decay=false"),
+				page(&[]),
+			)
+			.with_data(
+				format!("{USER_URL}?count=2&after=7&exclude_decay=false"),
+				page(&[]),
+			);
+		let client = client(&mock);
+		let laysar = UserIdentifier::Uuid(LAYSAR);
+		let params = GetMatchesParams::from(Pagination::count(2).unwrap());
+		let store = MemoryCheckpointStore::new();
+		store.save(KEY, 3).unwrap();
+
+		let sync = client
+			.sync_user_matches(&laysar, &params, &store)
+			.await
+			.unwrap();
+		let ids: Vec<_> = sync.matches.iter().map(|info| info.id).collect();
+		assert_eq!(ids, [7, 6, 5, 4]);
+		assert_eq!(sync.checkpoint, Some(7));
+		assert_eq!(store.load(KEY).unwrap(), Some(7));
+
+		let sync = client
+			.sync_user_matches(&laysar, &params, &store)
+			.await
+			.unwrap();
+		assert!(sync.matches.is_empty());
+		assert_eq!(sync.checkpoint, Some(7));
+	}
+
+	#[tokio::test]
+	async fn uncached_sync() {
+		let mock = MockTransport::new().with_data(
+			format!("{USER_URL}?count=2&after=3&exclude_decay=false"),
+			page(&[4]),
+		);
+		let cache = ResponseCache::default();
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.cache(cache.clone())
+			.build()
+			.unwrap();
+		let laysar = UserIdentifier::Uuid(LAYSAR);
+		let params = GetMatchesParams::from(Pagination::count(2).unwrap());
+
+		for _ in 0..2 {
+			let sync = client
+				.get_user_matches_after(&laysar, Some(3), &params)
+				.await
+				.unwrap();
+			assert_eq!(sync.checkpoint, Some(4));
+		}
+		assert_eq!(mock.requests().len(), 2);
+		assert!(cache.is_empty());
+	}
+
+	#[tokio::test]
+	async fn nickname_checkpoint() {
+		let fixture: Value = serde_json::from_str(include_str!(
+			"../../fixtures/users_79635c3d-bf63-4a22-8bf4-4544cc7c0d27.json"
+		))
+		.unwrap();
+		let mock = MockTransport::new()
+			.with_body(
+				"https://api.mcsrranked.com/users/LaysarOwO",
+				fixture["body"].to_string(),
+			)
+			.with_data(
+				"https://api.mcsrranked.com/users/LaysarOwO/matches?count=20&after=7&exclude_decay=false",
+				page(&[8]),
+			);
+		let client = client(&mock);
+		let store = MemoryCheckpointStore::new();
+		store.save(KEY, 7).unwrap();
+
+		let sync = client
+			.sync_user_matches(&UserIdentifier::Nickname("LaysarOwO"), None, &store)
+			.await
+			.unwrap();
+		let ids: Vec<_> = sync.matches.iter().map(|info| info.id).collect();
+		assert_eq!(ids, [8]);
+		assert_eq!(store.load(KEY).unwrap(), Some(8));
+		assert_eq!(store.load("LaysarOwO").unwrap(), None);
+	}
+
+	#[tokio::test]
+	async fn failed_sync() {
+		let client = client(&MockTransport::new());
+		let store = MemoryCheckpointStore::new();
+		store.save(KEY, 3).unwrap();
+
+		let sync = client
+			.sync_user_matches(&UserIdentifier::Uuid(LAYSAR), None, &store)
+			.await;
+		assert!(sync.is_err());
+		assert_eq!(store.load(KEY).unwrap(), Some(3));
+	}
+
+	#[test]
+	fn file_store() {
+		let dir = std::env::temp_dir().join(format!("mcsr-ranked-sync-{}", std::process::id()));
+		let store = FileCheckpointStore::new(dir.join("checkpoints.json"));
+		assert_eq!(store.load("LaysarOwO").unwrap(), None);
+
+		store.save("LaysarOwO", 1524115).unwrap();
+		store.save("doogile", 42).unwrap();
+		store.save("LaysarOwO", 1524116).unwrap();
+
+		let reopened = FileCheckpointStore::new(store.path());
+		assert_eq!(reopened.load("LaysarOwO").unwrap(), Some(1524116));
+		assert_eq!(reopened.load("doogile").unwrap(), Some(42));
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+}
+
+mod watch {
+	use std::{
+		collections::VecDeque,
+		sync::{Arc, Mutex},
+		time::Duration,
+	};
+
+	use futures_util::StreamExt;
+	use reqwest::StatusCode;
+	use serde_json::json;
+
+	use crate::{
+		client::{
+			HttpRequest, HttpResponse, RankedClient, ResponseCache, Transport, TransportFuture,
+		},
+		game::{MatchType, watch::WatchOptions},
+		types::MatchId,
+	};
+
+	use super::stream::page;
+
+	/// Serves the pages in order, repeating the last one
+	#[derive(Debug, Default)]
+	struct Pages {
+		pages: Mutex<VecDeque<Vec<MatchId>>>,
+		urls: Arc<Mutex<Vec<Box<str>>>>,
+	}
+
+	impl Transport for Pages {
+		fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+			self.urls.lock().unwrap().push(request.url.clone());
+			let mut pages = self.pages.lock().unwrap();
+			let ids = if pages.len() > 1 {
+				pages.pop_front().unwrap()
+			} else {
+				pages.front().cloned().unwrap_or_default()
+			};
+			let body = json!({ "status": "success", "data": page(&ids) });
+			let response = HttpResponse::new(StatusCode::OK, body.to_string());
+			Box::pin(async move { Ok(response) })
+		}
+	}
+
+	fn client(pages: &[&[MatchId]], urls: Arc<Mutex<Vec<Box<str>>>>) -> RankedClient {
+		let pages = Pages {
+			pages: Mutex::new(pages.iter().map(|ids| ids.to_vec()).collect()),
+			urls,
+		};
+		// The watcher must not be served the cached pages
+		RankedClient::builder()
+			.no_retry()
+			.no_rate_limit()
+			.transport(pages)
+			.cache(ResponseCache::default())
+			.build()
+			.unwrap()
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn watch_recent_matches() {
+		let urls = Arc::default();
+		let client = client(
+			&[&[2, 1], &[4, 3, 2], &[4, 3, 2], &[6, 5, 3]],
+			Arc::clone(&urls),
+		);
+		let options = WatchOptions::default()
+			.interval(Duration::from_secs(5))
+			.kind(MatchType::Ranked)
+			.tag("event");
+		let mut watcher = client.watch_recent_matches(options);
+
+		let mut ids = Vec::new();
+		for _ in 0..4 {
+			ids.push(watcher.next().await.unwrap().unwrap().id);
+		}
+		assert_eq!(ids, [3, 4, 5, 6]);
+		assert_eq!(
+			urls.lock().unwrap()[0].as_ref(),
+			"https://api.mcsrranked.com/matches?count=100&type=2&tag=event"
+		);
+
+		watcher.handle().shutdown();
+		assert!(watcher.next().await.is_none());
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn watch_pages_back() {
+		let urls = Arc::default();
+		let client = client(
+			&[&[2, 1], &[7, 6], &[5, 4], &[3, 2], &[7, 6]],
+			Arc::clone(&urls),
+		);
+		let options = WatchOptions::default().count(2).unwrap();
+		let mut watcher = client.watch_recent_matches(options);
+
+		let mut ids = Vec::new();
+		for _ in 0..5 {
+			ids.push(watcher.next().await.unwrap().unwrap().id);
+		}
+		assert_eq!(ids, [3, 4, 5, 6, 7]);
+		assert_eq!(
+			*urls.lock().unwrap(),
+			[
+				"https://api.mcsrranked.com/matches?count=2",
+				"https://api.mcsrranked.com/matches?count=2",
+				"https://api.mcsrranked.com/matches?count=2&before=6",
+				"https://api.mcsrranked.com/matches?count=2&before=4",
+			]
+			.map(Box::from)
+		);
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn watch_callback() {
+		let client = client(&[&[2, 1], &[3, 2]], Arc::default());
+		let watcher = client.watch_recent_matches(WatchOptions::default().emit_initial(true));
+		let handle = watcher.handle();
+
+		let ids = Arc::new(Mutex::new(Vec::new()));
+		watcher
+			.run(|info| {
+				let ids = Arc::clone(&ids);
+				let handle = handle.clone();
+				async move {
+					let mut ids = ids.lock().unwrap();
+					ids.push(info.unwrap().id);
+					if ids.len() == 3 {
+						handle.shutdown();
+					}
+				}
+			})
+			.await;
+		assert_eq!(*ids.lock().unwrap(), [1, 2, 3]);
+	}
+}
+
+mod timeline {
+	use uuid::Uuid;
+
+	use crate::{
+		game::{MatchTimelineEvent, timeline::TimelineEventKind},
+		types::Time,
+	};
+
+	#[test]
+	fn known_kinds() {
+		for (id, kind) in [
+			("story.enter_the_nether", TimelineEventKind::StoryEnterTheNether),
+			("nether.find_bastion", TimelineEventKind::NetherFindBastion),
+			("end.root", TimelineEventKind::EndRoot),
+			("projectelo.timeline.blind_travel", TimelineEventKind::BlindTravel),
+			("projectelo.timeline.reset", TimelineEventKind::Reset),
+		] {
+			let parsed: TimelineEventKind = serde_json::from_value(id.into()).unwrap();
+			assert_eq!(parsed, kind);
+			assert_eq!(parsed.as_str(), id);
+			assert_eq!(parsed.to_string(), id);
+		}
+		assert!(TimelineEventKind::NetherFindBastion.is_advancement());
+		assert!(!TimelineEventKind::BlindTravel.is_advancement());
+	}
+
+	#[test]
+	fn unknown_kind() {
+		let id = "projectelo.timeline.something_new";
+		let kind: TimelineEventKind = serde_json::from_value(id.into()).unwrap();
+		assert_eq!(kind, TimelineEventKind::Unknown(id.into()));
+		assert_eq!(kind.as_str(), id);
+		assert!(!kind.is_advancement());
+	}
+
+	#[test]
+	fn event_kind() {
+		let event = MatchTimelineEvent {
+			player_uuid: Uuid::nil(),
+			time: Time(118522),
+			id: "story.enter_the_nether".into(),
+		};
+		assert_eq!(event.kind(), TimelineEventKind::StoryEnterTheNether);
+	}
+
+	#[test]
+	fn known_id_normalized() {
+		for kind in [
+			TimelineEventKind::from("story.root"),
+			TimelineEventKind::from(String::from("story.root")),
+			serde_json::from_value("story.root".into()).unwrap(),
+		] {
+			assert!(matches!(kind, TimelineEventKind::StoryRoot));
+		}
+	}
+
+	#[cfg(feature = "serialize")]
+	#[test]
+	fn serialize_kind() {
+		for id in ["story.mine_stone", "some.custom_event"] {
+			let kind = TimelineEventKind::from(id);
+			assert_eq!(serde_json::to_value(&kind).unwrap(), id);
+		}
+	}
+}
+
+mod splits {
+	use serde_json::{Value, json};
+	use uuid::uuid;
+
+	use crate::{
+		game::{AdvancedMatchInfo, splits::Split},
+		types::Time,
+	};
+
+	pub(super) fn match_1524115() -> Value {
+		serde_json::from_str(super::MATCH_1524115).unwrap()
+	}
+
+	#[test]
+	fn match_splits() {
+		let info: AdvancedMatchInfo = serde_json::from_value(match_1524115()).unwrap();
+		let splits = info.splits();
+		let frigidbob = splits
+			.player(uuid!("7d3a6bb9f62645ae80cf40840ca84c50"))
+			.unwrap();
+		assert_eq!(
+			frigidbob.splits().collect::<Vec<_>>(),
+			[
+				(Split::EnterNether, Some(Time(118522))),
+				(Split::FindBastion, Some(Time(161055))),
+				(Split::FindFortress, Some(Time(310597))),
+				(Split::BlindTravel, Some(Time(358761))),
+				(Split::Stronghold, Some(Time(454844))),
+				(Split::EnterEnd, Some(Time(535393))),
+				(Split::DragonDeath, Some(Time(610048))),
+				(Split::Completion, Some(Time(620128))),
+			]
+		);
+		assert_eq!(frigidbob.duration(Split::EnterNether), Some(Time(118522)));
+		assert_eq!(frigidbob.duration(Split::FindBastion), Some(Time(42533)));
+		assert_eq!(frigidbob.duration(Split::Completion), Some(Time(10080)));
+		assert!(frigidbob.completed());
+		assert_eq!(frigidbob.resets, 0);
+
+		let laysar = splits
+			.player(uuid!("79635c3dbf634a228bf44544cc7c0d27"))
+			.unwrap();
+		assert_eq!(laysar.time(Split::Stronghold), Some(Time(494792)));
+		assert_eq!(
+			laysar.missing().collect::<Vec<_>>(),
+			[Split::EnterEnd, Split::DragonDeath, Split::Completion]
+		);
+		assert!(!laysar.completed());
+
+		assert_eq!(
+			splits.best(Split::FindBastion),
+			Some((uuid!("79635c3dbf634a228bf44544cc7c0d27"), Time(157338)))
+		);
+	}
+
+	#[test]
+	fn resets() {
+		let mut info = match_1524115();
+		let uuid = "79635c3dbf634a228bf44544cc7c0d27";
+		info["timelines"] = json!([
+			{ "uuid": uuid, "time": 200000, "type": "nether.find_fortress" },
+			{ "uuid": uuid, "time": 190000, "type": "nether.find_bastion" },
+			{ "uuid": uuid, "time": 150000, "type": "story.enter_the_nether" },
+			{ "uuid": uuid, "time": 90000, "type": "projectelo.timeline.reset" },
+			{ "uuid": uuid, "time": 80000, "type": "nether.find_bastion" },
+			{ "uuid": uuid, "time": 60000, "type": "story.enter_the_nether" },
+		]);
+		let info: AdvancedMatchInfo = serde_json::from_value(info).unwrap();
+		let splits = info.splits();
+		let laysar = splits.player(uuid.parse().unwrap()).unwrap();
+
+		assert_eq!(laysar.resets, 1);
+		assert_eq!(laysar.last_reset, Some(Time(90000)));
+		assert_eq!(laysar.time(Split::EnterNether), Some(Time(150000)));
+		assert_eq!(laysar.duration(Split::EnterNether), Some(Time(60000)));
+		assert_eq!(laysar.time(Split::FindBastion), Some(Time(190000)));
+		assert_eq!(laysar.duration(Split::FindFortress), Some(Time(10000)));
+		assert_eq!(laysar.duration(Split::BlindTravel), None);
+	}
+
+	#[test]
+	fn reset_after_split() {
+		let mut info = match_1524115();
+		let uuid = "7d3a6bb9f62645ae80cf40840ca84c50";
+		info["timelines"] = json!([
+			{ "uuid": uuid, "time": 630000, "type": "projectelo.timeline.reset" },
+			{ "uuid": uuid, "time": 100000, "type": "story.enter_the_nether" },
+		]);
+		let info: AdvancedMatchInfo = serde_json::from_value(info).unwrap();
+		let splits = info.splits();
+		let frigidbob = splits.player(uuid.parse().unwrap()).unwrap();
+
+		assert_eq!(frigidbob.last_reset, Some(Time(630000)));
+		assert_eq!(frigidbob.time(Split::EnterNether), None);
+		assert_eq!(frigidbob.time(Split::Completion), Some(Time(620128)));
+		assert_eq!(frigidbob.duration(Split::Completion), Some(Time(620128)));
+	}
+}
+
+mod perspective {
+	use uuid::uuid;
+
+	use super::splits::match_1524115;
+	use crate::{
+		game::{AdvancedMatchInfo, perspective::PlayerOutcome},
+		types::Time,
+	};
+
+	#[test]
+	fn perspective() {
+		let info: AdvancedMatchInfo = serde_json::from_value(match_1524115()).unwrap();
+		let frigidbob_uuid = uuid!("7d3a6bb9f62645ae80cf40840ca84c50");
+		let laysar_uuid = uuid!("79635c3dbf634a228bf44544cc7c0d27");
+
+		let frigidbob = info.info.perspective(frigidbob_uuid).unwrap();
+		assert_eq!(frigidbob.player().nickname.as_ref(), "Frigbob");
+		assert_eq!(frigidbob.opponent().unwrap().uuid, laysar_uuid);
+		assert_eq!(frigidbob.outcome(), PlayerOutcome::Win);
+		assert!(!frigidbob.forfeited());
+		assert_eq!(frigidbob.elo_change(), Some(19));
+		assert_eq!(frigidbob.elo_after(), Some(1282));
+		assert_eq!(frigidbob.elo_before(), Some(1263));
+		assert_eq!(frigidbob.opponent_elo_change(), Some(-19));
+		assert_eq!(frigidbob.opponent_elo_before(), Some(1264));
+		assert_eq!(frigidbob.completion_time(), Some(Time(620128)));
+
+		let laysar = info.perspective(laysar_uuid).unwrap();
+		assert_eq!(laysar.outcome(), PlayerOutcome::Loss);
+		assert_eq!(laysar.opponent().unwrap().uuid, frigidbob_uuid);
+		assert_eq!(laysar.elo_before(), Some(1264));
+		assert_eq!(laysar.completion_time(), None);
+
+		assert!(info.perspective(uuid!("bbc886da1b024739b4b80f1542e9f61d")).is_none());
+	}
+
+	#[test]
+	fn forfeit_and_draw() {
+		let mut data = match_1524115();
+		data["forfeited"] = true.into();
+		let info: AdvancedMatchInfo = serde_json::from_value(data.clone()).unwrap();
+		let laysar = info
+			.info
+			.perspective(uuid!("79635c3dbf634a228bf44544cc7c0d27"))
+			.unwrap();
+		assert!(laysar.forfeited());
+		assert!(!laysar.opponent_forfeited());
+		let frigidbob = info
+			.info
+			.perspective(uuid!("7d3a6bb9f62645ae80cf40840ca84c50"))
+			.unwrap();
+		assert!(frigidbob.opponent_forfeited());
+		assert_eq!(frigidbob.completion_time(), None);
+
+		data["result"]["uuid"] = serde_json::Value::Null;
+		let info: AdvancedMatchInfo = serde_json::from_value(data).unwrap();
+		let frigidbob = info
+			.perspective(uuid!("7d3a6bb9f62645ae80cf40840ca84c50"))
+			.unwrap();
+		assert_eq!(frigidbob.outcome(), PlayerOutcome::Draw);
+		assert!(!frigidbob.forfeited());
+	}
+}
+
+mod elo_history {
+	use serde_json::{Value, json};
+	use uuid::{Uuid, uuid};
+
+	use super::stream::match_json;
+	use crate::{
+		client::{MockTransport, RankedClient},
+		game::{MatchInfo, elo_history::EloHistory},
+		types::{MatchId, Season},
+		user::identifier::UserIdentifier,
+	};
+
+	const LAYSAR: Uuid = uuid!("79635c3dbf634a228bf44544cc7c0d27");
+
+	fn elo_match(id: MatchId, season: Season, elo: u16, change: i16, decayed: bool) -> Value {
+		let mut info = match_json(id);
+		info["season"] = season.into();
+		info["date"] = (1735326765 + id * 1000).into();
+		info["decayed"] = decayed.into();
+		info["players"] = json!([{
+			"uuid": LAYSAR,
+			"nickname": "LaysarOwO",
+			"roleType": 0,
+			"eloRate": 1146,
+			"eloRank": 1048,
+			"country": null
+		}]);
+		info["changes"] = json!([{ "uuid": LAYSAR, "change": change, "eloRate": elo }]);
+		info
+	}
+
+	fn matches() -> Value {
+		// Newest first, as sent by the API
+		json!([
+			elo_match(5, 7, 1180, -20, true),
+			elo_match(4, 7, 1200, 25, false),
+			elo_match(3, 7, 1175, -25, false),
+			elo_match(2, 6, 1300, 30, false),
+			elo_match(1, 6, 1270, 20, false),
+		])
+	}
+
+	#[test]
+	fn from_matches() {
+		let matches: Vec<MatchInfo> = serde_json::from_value(matches()).unwrap();
+		let history = EloHistory::from_matches(LAYSAR, &matches);
+
+		let ids: Vec<_> = history.points().iter().map(|point| point.match_id).collect();
+		assert_eq!(ids, [1, 2, 3, 4, 5]);
+		assert_eq!(history.last().unwrap().elo, 1180);
+		assert_eq!(history.highest().unwrap().match_id, 2);
+		assert_eq!(history.lowest().unwrap().match_id, 3);
+		assert_eq!(history.played().count(), 4);
+		assert_eq!(history.decay_loss(), 20);
+
+		let seasons: Vec<_> = history
+			.seasons()
+			.map(|(season, points)| (season, points.len()))
+			.collect();
+		assert_eq!(seasons, [(6, 2), (7, 3)]);
+		assert_eq!(history.season(7)[0].match_id, 3);
+		assert!(history.season(5).is_empty());
+
+		let other = EloHistory::from_matches(Uuid::nil(), &matches);
+		assert!(other.points().is_empty());
+	}
+
+	#[tokio::test]
+	async fn get_user_elo_history() {
+		let mock = MockTransport::new().with_data(
+			format!(
+				"https://api.mcsrranked.com/users/{}/matches?count=20&exclude_decay=false",
+				LAYSAR.hyphenated()
+			),
+			matches(),
+		);
+		let client = RankedClient::builder()
+			.no_retry()
+			.transport(mock.clone())
+			.build()
+			.unwrap();
+		let history = client
+			.get_user_elo_history(&UserIdentifier::Uuid(LAYSAR), None)
+			.await
+			.unwrap();
+		assert_eq!(history.player_uuid, LAYSAR);
+		assert_eq!(history.points().len(), 5);
+		assert_eq!(mock.requests().len(), 1);
+	}
+}
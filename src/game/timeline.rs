@@ -0,0 +1,189 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Deserializer};
+#[cfg(feature = "serialize")]
+use serde::{Serialize, Serializer};
+
+macro_rules! timeline_event_kinds {
+	($($(#[$attr:meta])* $variant:ident => $id:literal,)*) => {
+		/// Kind of a timeline event, parsed from its id such as `story.enter_the_nether`
+		///
+		/// Covers the Minecraft advancements and the `projectelo.timeline` events,
+		/// any other id is kept as [`TimelineEventKind::Unknown`].
+		/// Serializes back to the original id
+		#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+		#[non_exhaustive]
+		pub enum TimelineEventKind {
+			$($(#[$attr])* $variant,)*
+			/// An id not known to this crate
+			///
+			/// Never holds a known id when constructed by [`TimelineEventKind::from`] or deserialized
+			Unknown(Box<str>),
+		}
+
+		impl TimelineEventKind {
+			/// The id of the event, as sent by the API
+			pub fn as_str(&self) -> &str {
+				match self {
+					$(Self::$variant => $id,)*
+					Self::Unknown(id) => id,
+				}
+			}
+
+			fn known(id: &str) -> Option<Self> {
+				match id {
+					$($id => Some(Self::$variant),)*
+					_ => None,
+				}
+			}
+		}
+	};
+}
+
+timeline_event_kinds! {
+	StoryRoot => "story.root",
+	StoryMineStone => "story.mine_stone",
+	StoryUpgradeTools => "story.upgrade_tools",
+	StorySmeltIron => "story.smelt_iron",
+	StoryObtainArmor => "story.obtain_armor",
+	StoryLavaBucket => "story.lava_bucket",
+	StoryIronTools => "story.iron_tools",
+	StoryDeflectArrow => "story.deflect_arrow",
+	StoryFormObsidian => "story.form_obsidian",
+	StoryMineDiamond => "story.mine_diamond",
+	StoryEnterTheNether => "story.enter_the_nether",
+	StoryShinyGear => "story.shiny_gear",
+	StoryEnchantItem => "story.enchant_item",
+	StoryCureZombieVillager => "story.cure_zombie_villager",
+	StoryFollowEnderEye => "story.follow_ender_eye",
+	StoryEnterTheEnd => "story.enter_the_end",
+
+	NetherRoot => "nether.root",
+	NetherReturnToSender => "nether.return_to_sender",
+	NetherFindBastion => "nether.find_bastion",
+	NetherObtainAncientDebris => "nether.obtain_ancient_debris",
+	NetherFastTravel => "nether.fast_travel",
+	NetherFindFortress => "nether.find_fortress",
+	NetherObtainCryingObsidian => "nether.obtain_crying_obsidian",
+	NetherDistractPiglin => "nether.distract_piglin",
+	NetherRideStrider => "nether.ride_strider",
+	NetherUneasyAlliance => "nether.uneasy_alliance",
+	NetherLootBastion => "nether.loot_bastion",
+	NetherUseLodestone => "nether.use_lodestone",
+	NetherNetheriteArmor => "nether.netherite_armor",
+	NetherGetWitherSkull => "nether.get_wither_skull",
+	NetherObtainBlazeRod => "nether.obtain_blaze_rod",
+	NetherChargeRespawnAnchor => "nether.charge_respawn_anchor",
+	NetherExploreNether => "nether.explore_nether",
+	NetherSummonWither => "nether.summon_wither",
+	NetherBrewPotion => "nether.brew_potion",
+	NetherCreateBeacon => "nether.create_beacon",
+	NetherAllPotions => "nether.all_potions",
+	NetherCreateFullBeacon => "nether.create_full_beacon",
+	NetherAllEffects => "nether.all_effects",
+
+	EndRoot => "end.root",
+	EndKillDragon => "end.kill_dragon",
+	EndDragonEgg => "end.dragon_egg",
+	EndEnterEndGateway => "end.enter_end_gateway",
+	EndRespawnDragon => "end.respawn_dragon",
+	EndDragonBreath => "end.dragon_breath",
+	EndFindEndCity => "end.find_end_city",
+	EndElytra => "end.elytra",
+	EndLevitate => "end.levitate",
+
+	AdventureRoot => "adventure.root",
+	AdventureVoluntaryExile => "adventure.voluntary_exile",
+	AdventureKillAMob => "adventure.kill_a_mob",
+	AdventureTrade => "adventure.trade",
+	AdventureHoneyBlockSlide => "adventure.honey_block_slide",
+	AdventureOlBetsy => "adventure.ol_betsy",
+	AdventureSleepInBed => "adventure.sleep_in_bed",
+	AdventureHeroOfTheVillage => "adventure.hero_of_the_village",
+	AdventureThrowTrident => "adventure.throw_trident",
+	AdventureShootArrow => "adventure.shoot_arrow",
+	AdventureKillAllMobs => "adventure.kill_all_mobs",
+	AdventureTotemOfUndying => "adventure.totem_of_undying",
+	AdventureSummonIronGolem => "adventure.summon_iron_golem",
+	AdventureTwoBirdsOneArrow => "adventure.two_birds_one_arrow",
+	AdventureWhosThePillagerNow => "adventure.whos_the_pillager_now",
+	AdventureArbalistic => "adventure.arbalistic",
+	AdventureAdventuringTime => "adventure.adventuring_time",
+	AdventureVeryVeryFrightening => "adventure.very_very_frightening",
+	AdventureSniperDuel => "adventure.sniper_duel",
+	AdventureBullseye => "adventure.bullseye",
+
+	HusbandryRoot => "husbandry.root",
+	HusbandrySafelyHarvestHoney => "husbandry.safely_harvest_honey",
+	HusbandryBreedAnAnimal => "husbandry.breed_an_animal",
+	HusbandryTameAnAnimal => "husbandry.tame_an_animal",
+	HusbandryFishyBusiness => "husbandry.fishy_business",
+	HusbandrySilkTouchNest => "husbandry.silk_touch_nest",
+	HusbandryPlantSeed => "husbandry.plant_seed",
+	HusbandryBredAllAnimals => "husbandry.bred_all_animals",
+	HusbandryCompleteCatalogue => "husbandry.complete_catalogue",
+	HusbandryTacticalFishing => "husbandry.tactical_fishing",
+	HusbandryBalancedDiet => "husbandry.balanced_diet",
+	HusbandryObtainNetheriteHoe => "husbandry.obtain_netherite_hoe",
+
+	/// The player threw an eye of ender from the nether to the stronghold
+	BlindTravel => "projectelo.timeline.blind_travel",
+	/// The player killed the ender dragon
+	DragonDeath => "projectelo.timeline.dragon_death",
+	/// The player reset their world
+	Reset => "projectelo.timeline.reset",
+	/// The player died
+	Death => "projectelo.timeline.death",
+	/// The player died and respawned at their spawnpoint
+	DeathSpawnpoint => "projectelo.timeline.death_spawnpoint",
+	/// The player forfeited the match
+	Forfeit => "projectelo.timeline.forfeit",
+}
+
+impl TimelineEventKind {
+	/// Whether the event is a Minecraft advancement
+	pub fn is_advancement(&self) -> bool {
+		let (category, _) = self.as_str().split_once('.').unwrap_or_default();
+		matches!(
+			category,
+			"story" | "nether" | "end" | "adventure" | "husbandry"
+		)
+	}
+}
+
+impl From<&str> for TimelineEventKind {
+	fn from(id: &str) -> Self {
+		Self::known(id).unwrap_or_else(|| Self::Unknown(id.into()))
+	}
+}
+
+impl From<String> for TimelineEventKind {
+	fn from(id: String) -> Self {
+		Self::known(&id).unwrap_or_else(|| Self::Unknown(id.into()))
+	}
+}
+
+impl Display for TimelineEventKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl<'de> Deserialize<'de> for TimelineEventKind {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		String::deserialize(deserializer).map(Self::from)
+	}
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for TimelineEventKind {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str(self.as_str())
+	}
+}
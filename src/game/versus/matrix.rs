@@ -0,0 +1,314 @@
+use std::{
+	fmt::{self, Display},
+	io,
+};
+
+use futures_util::{StreamExt, stream};
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use uuid::Uuid;
+
+#[cfg(feature = "blocking")]
+use crate::client::blocking::BlockingRankedClient;
+use crate::{
+	Error, Result,
+	client::RankedClient,
+	types::EloChange,
+	user::{UserProfile, identifier::UserIdentifier},
+};
+
+use super::{VersusInfo, VersusOutcome, requests::GetVersusInfoParams};
+
+/// Amount of versus requests [`RankedClient::get_versus_matrix`] keeps in flight
+///
+/// The requests still wait on the client's rate limiter
+pub const MATRIX_CONCURRENCY: usize = 8;
+
+/// Wins and losses of a player against an opponent
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WinLoss {
+	pub wins: u32,
+	pub losses: u32,
+	/// Amount of matches played, including draws
+	pub total: u32,
+}
+
+impl WinLoss {
+	fn new(outcome: &VersusOutcome, player: Uuid, opponent: Uuid) -> Self {
+		Self {
+			wins: outcome.wins.get(player).copied().unwrap_or_default(),
+			losses: outcome.wins.get(opponent).copied().unwrap_or_default(),
+			total: outcome.total,
+		}
+	}
+}
+
+/// Record of a player against a single opponent
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VersusRecord {
+	pub ranked: WinLoss,
+	pub casual: WinLoss,
+	/// Net elo the player gained from the opponent
+	pub elo_change: EloChange,
+}
+
+impl VersusRecord {
+	/// Record of the `player` against the `opponent` in the versus `info`
+	pub fn new(info: &VersusInfo, player: Uuid, opponent: Uuid) -> Self {
+		Self {
+			ranked: WinLoss::new(&info.results.ranked, player, opponent),
+			casual: WinLoss::new(&info.results.casual, player, opponent),
+			elo_change: info.changes.get(player).copied().unwrap_or_default(),
+		}
+	}
+}
+
+/// Player of a [`VersusMatrix`]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixPlayer {
+	/// The identifier the player was requested by
+	pub identifier: Box<str>,
+	/// The player's profile, known once any of their versus info was fetched
+	pub profile: Option<UserProfile>,
+}
+
+impl MatrixPlayer {
+	/// Whether the `profile` belongs to the requested identifier, as far as it can be told
+	fn is(&self, profile: &UserProfile) -> bool {
+		self.identifier.eq_ignore_ascii_case(&profile.nickname)
+			|| *self.identifier == *profile.uuid.hyphenated().to_string()
+	}
+	/// The player's nickname if known, the identifier otherwise
+	pub fn name(&self) -> &str {
+		match &self.profile {
+			Some(profile) => &profile.nickname,
+			None => &self.identifier,
+		}
+	}
+}
+
+/// Head-to-head records of every pair of players of a roster
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersusMatrix {
+	/// The players, in the order they were requested in
+	pub players: Box<[MatrixPlayer]>,
+	/// Record of every player (row) against every opponent (column),
+	/// `None` on the diagonal and for the pairs which could not be fetched
+	pub records: Box<[Box<[Option<VersusRecord>]>]>,
+}
+
+impl VersusMatrix {
+	fn new(identifiers: &[UserIdentifier<'_>]) -> Self {
+		let len = identifiers.len();
+		Self {
+			players: identifiers
+				.iter()
+				.map(|identifier| MatrixPlayer {
+					identifier: identifier.to_string().into(),
+					profile: None,
+				})
+				.collect(),
+			records: (0..len).map(|_| vec![None; len].into()).collect(),
+		}
+	}
+
+	/// Fill in the versus `info` of the players at `row` and `column`
+	fn insert(&mut self, row: usize, column: usize, info: &VersusInfo) {
+		let (mut player, mut opponent) = (&info.players.0, &info.players.1);
+		if self.players[row].is(opponent) && !self.players[row].is(player) {
+			std::mem::swap(&mut player, &mut opponent);
+		}
+		self.records[row][column] = Some(VersusRecord::new(info, player.uuid, opponent.uuid));
+		self.records[column][row] = Some(VersusRecord::new(info, opponent.uuid, player.uuid));
+		self.players[row]
+			.profile
+			.get_or_insert_with(|| player.clone());
+		self.players[column]
+			.profile
+			.get_or_insert_with(|| opponent.clone());
+	}
+
+	/// Amount of players
+	pub fn len(&self) -> usize {
+		self.players.len()
+	}
+	/// Whether the matrix has no players
+	pub fn is_empty(&self) -> bool {
+		self.players.is_empty()
+	}
+	/// Record of the player at index `player` against the one at index `opponent`
+	pub fn record(&self, player: usize, opponent: usize) -> Option<&VersusRecord> {
+		self.records.get(player)?.get(opponent)?.as_ref()
+	}
+
+	/// Write the records as CSV, one row per player and opponent
+	pub fn write_csv(&self, mut writer: impl io::Write) -> io::Result<()> {
+		writeln!(
+			writer,
+			"player,opponent,ranked_wins,ranked_losses,ranked_total,casual_wins,casual_losses,casual_total,elo_change"
+		)?;
+		for (row, player) in self.players.iter().enumerate() {
+			for (column, opponent) in self.players.iter().enumerate() {
+				let Some(record) = self.record(row, column) else {
+					continue;
+				};
+				writeln!(
+					writer,
+					"{},{},{},{},{},{},{},{},{}",
+					csv_field(player.name()),
+					csv_field(opponent.name()),
+					record.ranked.wins,
+					record.ranked.losses,
+					record.ranked.total,
+					record.casual.wins,
+					record.casual.losses,
+					record.casual.total,
+					record.elo_change,
+				)?;
+			}
+		}
+		Ok(())
+	}
+	/// The records as CSV, see [`VersusMatrix::write_csv`]
+	pub fn to_csv(&self) -> String {
+		let mut csv = Vec::new();
+		self.write_csv(&mut csv)
+			.expect("Expected writing to a Vec to succeed");
+		String::from_utf8(csv).expect("Expected the CSV to be valid UTF-8")
+	}
+	/// The players and records as JSON
+	#[cfg(feature = "serialize")]
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string(self)
+	}
+}
+
+/// Failed request of a single pair of a [`VersusMatrix`]
+#[derive(Debug)]
+pub struct PairError {
+	/// Index of the first player of the pair
+	pub player: usize,
+	/// Index of the second player of the pair
+	pub opponent: usize,
+	pub error: Error,
+}
+
+impl Display for PairError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Versus request of players {} and {} failed: {}",
+			self.player, self.opponent, self.error
+		)
+	}
+}
+impl std::error::Error for PairError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+/// Versus matrix of the pairs which could be fetched, along with the errors of the others
+#[derive(Debug)]
+pub struct VersusMatrixReport {
+	pub matrix: VersusMatrix,
+	/// Errors of the failed pairs, their records are left empty
+	pub errors: Vec<PairError>,
+}
+
+impl VersusMatrixReport {
+	/// Whether the versus info of every pair was fetched
+	pub fn is_complete(&self) -> bool {
+		self.errors.is_empty()
+	}
+	/// The matrix if it is complete, the first error otherwise
+	pub fn into_result(self) -> Result<VersusMatrix> {
+		match self.errors.into_iter().next() {
+			Some(failure) => Err(failure.error),
+			None => Ok(self.matrix),
+		}
+	}
+}
+
+/// Quote a CSV field if needed
+fn csv_field(value: &str) -> std::borrow::Cow<'_, str> {
+	if value.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", value.replace('"', "\"\"")).into()
+	} else {
+		value.into()
+	}
+}
+
+/// Index pairs of every two different players
+fn pairs(len: usize) -> impl Iterator<Item = (usize, usize)> {
+	(0..len).flat_map(move |row| (row + 1..len).map(move |column| (row, column)))
+}
+
+impl RankedClient {
+	/// GET the versus info of every pair of `players` and build their head-to-head matrix
+	///
+	/// Up to [`MATRIX_CONCURRENCY`] requests are sent at once, within the client's rate limit.
+	/// A failed request leaves its pair empty and is recorded in [`VersusMatrixReport::errors`]
+	pub async fn get_versus_matrix<'a>(
+		&self,
+		players: &[UserIdentifier<'_>],
+		params: impl Into<Option<&'a GetVersusInfoParams>>,
+	) -> VersusMatrixReport {
+		let params = params.into();
+		let mut matrix = VersusMatrix::new(players);
+		let mut errors = Vec::new();
+		let mut responses = stream::iter(pairs(players.len()))
+			.map(|(row, column)| async move {
+				let info = self
+					.get_versus(&players[row], &players[column], params)
+					.await;
+				(row, column, info)
+			})
+			.buffer_unordered(MATRIX_CONCURRENCY);
+		while let Some((row, column, info)) = responses.next().await {
+			match info {
+				Ok(info) => matrix.insert(row, column, &info),
+				Err(error) => errors.push(PairError {
+					player: row,
+					opponent: column,
+					error,
+				}),
+			}
+		}
+		// Completion order depends on the responses
+		errors.sort_by_key(|failure| (failure.player, failure.opponent));
+		VersusMatrixReport { matrix, errors }
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the versus info of every pair of `players` and build their head-to-head matrix
+	///
+	/// The requests are sent one after another.
+	/// A failed request leaves its pair empty and is recorded in [`VersusMatrixReport::errors`]
+	pub fn get_versus_matrix<'a>(
+		&self,
+		players: &[UserIdentifier<'_>],
+		params: impl Into<Option<&'a GetVersusInfoParams>>,
+	) -> VersusMatrixReport {
+		let params = params.into();
+		let mut matrix = VersusMatrix::new(players);
+		let mut errors = Vec::new();
+		for (row, column) in pairs(players.len()) {
+			match self.get_versus(&players[row], &players[column], params) {
+				Ok(info) => matrix.insert(row, column, &info),
+				Err(error) => errors.push(PairError {
+					player: row,
+					opponent: column,
+					error,
+				}),
+			}
+		}
+		VersusMatrixReport { matrix, errors }
+	}
+}
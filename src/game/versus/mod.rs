@@ -8,6 +8,7 @@ use crate::{
 	user::UserProfile,
 };
 
+pub mod matrix;
 pub mod requests;
 #[cfg(test)]
 mod tests;
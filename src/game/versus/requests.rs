@@ -1,15 +1,22 @@
 use serde::Serialize;
 
-#[cfg(feature = "matches")]
-use crate::game::{MatchInfo, requests::GetMatchesParams};
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
-use crate::{Result, helpers::make_request, types::Season, user::identifier::UserIdentifier};
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+#[cfg(all(feature = "matches", feature = "blocking"))]
+use crate::game::stream::MatchIter;
+#[cfg(feature = "matches")]
+use crate::game::{MatchInfo, requests::GetMatchesParams, stream::MatchStream};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	types::Season,
+	user::identifier::UserIdentifier,
+};
 
 use super::VersusInfo;
 
-const BASE_URL: &str = "https://api.mcsrranked.com/users/{}/versus/{}";
-const MATCHES_URL: &str = "https://api.mcsrranked.com/users/{}/versus/{}";
+const BASE_PATH: &str = "users/{}/versus/{}";
+const MATCHES_PATH: &str = "users/{}/versus/{}/matches";
 
 /// Parameters for [`VersusInfo::get`]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -25,15 +32,16 @@ impl GetVersusInfoParams {
 	}
 }
 
-impl VersusInfo {
+impl RankedClient {
 	/// GET versus info between two players using `params`
-	pub async fn get<'a>(
-		user_1: &UserIdentifier<'a>,
-		user_2: &UserIdentifier<'a>,
+	pub async fn get_versus<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
 		params: impl Into<Option<&'a GetVersusInfoParams>>,
-	) -> Result<Self> {
-		make_request(
-			BASE_URL,
+	) -> Result<VersusInfo> {
+		self.request(
+			BASE_PATH,
 			[&user_1.to_string(), &user_2.to_string()],
 			params.into(),
 		)
@@ -41,18 +49,115 @@ impl VersusInfo {
 	}
 	#[cfg(feature = "matches")]
 	/// GET versus matches between two players using `params`
-	pub async fn get_matches<'a>(
-		user_1: &UserIdentifier<'a>,
-		user_2: &UserIdentifier<'a>,
+	pub async fn get_versus_matches<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
 		params: impl Into<Option<&'a GetMatchesParams>>,
 	) -> Result<Vec<MatchInfo>> {
-		make_request(
-			MATCHES_URL,
+		self.request(
+			MATCHES_PATH,
 			[&user_1.to_string(), &user_2.to_string()],
 			params.into(),
 		)
 		.await
 	}
+	#[cfg(feature = "matches")]
+	/// Stream all matches between two players, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchStream`]
+	pub fn stream_versus_matches<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchStream {
+		MatchStream::new(
+			self.clone(),
+			MATCHES_PATH,
+			Box::new([user_1.to_string(), user_2.to_string()]),
+			params.into().cloned().unwrap_or_default(),
+		)
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET versus info between two players using `params`
+	pub fn get_versus<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetVersusInfoParams>>,
+	) -> Result<VersusInfo> {
+		self.request(
+			BASE_PATH,
+			[&user_1.to_string(), &user_2.to_string()],
+			params.into(),
+		)
+	}
+	#[cfg(feature = "matches")]
+	/// Synchronously GET versus matches between two players using `params`
+	pub fn get_versus_matches<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<Vec<MatchInfo>> {
+		self.request(
+			MATCHES_PATH,
+			[&user_1.to_string(), &user_2.to_string()],
+			params.into(),
+		)
+	}
+	#[cfg(feature = "matches")]
+	/// Iterate over all matches between two players, newest first
+	///
+	/// Pages are requested using the count and filters of `params`, see [`MatchIter`]
+	pub fn iter_versus_matches<'a>(
+		&self,
+		user_1: &UserIdentifier<'_>,
+		user_2: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchIter {
+		MatchIter::new(
+			self.clone(),
+			MATCHES_PATH,
+			Box::new([user_1.to_string(), user_2.to_string()]),
+			params.into().cloned().unwrap_or_default(),
+		)
+	}
+}
+
+impl VersusInfo {
+	/// GET versus info between two players using `params`
+	pub async fn get<'a>(
+		user_1: &UserIdentifier<'a>,
+		user_2: &UserIdentifier<'a>,
+		params: impl Into<Option<&'a GetVersusInfoParams>>,
+	) -> Result<Self> {
+		default_client().get_versus(user_1, user_2, params).await
+	}
+	#[cfg(feature = "matches")]
+	/// GET versus matches between two players using `params`
+	pub async fn get_matches<'a>(
+		user_1: &UserIdentifier<'a>,
+		user_2: &UserIdentifier<'a>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> Result<Vec<MatchInfo>> {
+		default_client()
+			.get_versus_matches(user_1, user_2, params)
+			.await
+	}
+	#[cfg(feature = "matches")]
+	/// Stream all matches between two players, newest first, see [`MatchStream`]
+	pub fn stream_matches<'a>(
+		user_1: &UserIdentifier<'a>,
+		user_2: &UserIdentifier<'a>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchStream {
+		default_client().stream_versus_matches(user_1, user_2, params)
+	}
 }
 
 #[cfg(feature = "blocking")]
@@ -63,11 +168,7 @@ impl VersusInfo {
 		user_2: &UserIdentifier<'a>,
 		params: impl Into<Option<&'a GetVersusInfoParams>>,
 	) -> Result<Self> {
-		make_request_blocking(
-			BASE_URL,
-			[&user_1.to_string(), &user_2.to_string()],
-			params.into(),
-		)
+		default_blocking_client().get_versus(user_1, user_2, params)
 	}
 	#[cfg(feature = "matches")]
 	/// Synchronounsly GET versus matches between two players using `params`
@@ -76,10 +177,15 @@ impl VersusInfo {
 		user_2: &UserIdentifier<'a>,
 		params: impl Into<Option<&'a GetMatchesParams>>,
 	) -> Result<Vec<MatchInfo>> {
-		make_request_blocking(
-			MATCHES_URL,
-			[&user_1.to_string(), &user_2.to_string()],
-			params.into(),
-		)
+		default_blocking_client().get_versus_matches(user_1, user_2, params)
+	}
+	#[cfg(feature = "matches")]
+	/// Iterate over all matches between two players, newest first, see [`MatchIter`]
+	pub fn iter_matches_blocking<'a>(
+		user_1: &UserIdentifier<'a>,
+		user_2: &UserIdentifier<'a>,
+		params: impl Into<Option<&'a GetMatchesParams>>,
+	) -> MatchIter {
+		default_blocking_client().iter_versus_matches(user_1, user_2, params)
 	}
 }
@@ -70,3 +70,135 @@ fn versus() {
 		);
 	}
 }
+
+#[tokio::test]
+async fn versus_matches_stream() {
+	use futures_util::TryStreamExt;
+
+	use crate::{
+		client::{MockTransport, RankedClient},
+		game::{requests::GetMatchesParams, tests::stream::page},
+		pagination::Pagination,
+		user::identifier::UserIdentifier,
+	};
+
+	const URL: &str = "https://api.mcsrranked.com/users/jamyreaf/versus/Priffin/matches";
+	let mock = MockTransport::new()
+		.with_data(format!("{URL}?count=2&exclude_decay=false"), page(&[9, 7]))
+		.with_data(
+			format!("{URL}?count=2&before=7&exclude_decay=false"),
+			page(&[4]),
+		);
+	let client = RankedClient::builder()
+		.no_retry()
+		.transport(mock.clone())
+		.build()
+		.unwrap();
+
+	let params = GetMatchesParams::from(Pagination::count(2).unwrap());
+	let ids: Vec<_> = client
+		.stream_versus_matches(
+			&UserIdentifier::Nickname("jamyreaf"),
+			&UserIdentifier::Nickname("Priffin"),
+			&params,
+		)
+		.map_ok(|info| info.id)
+		.try_collect()
+		.await
+		.unwrap();
+	assert_eq!(ids, [9, 7, 4]);
+	assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn versus_matrix() {
+	use serde_json::{Value, json};
+
+	use crate::{
+		client::{MockTransport, RankedClient},
+		user::identifier::UserIdentifier,
+	};
+
+	fn player(uuid: &str, nickname: &str) -> Value {
+		json!({ "uuid": uuid, "nickname": nickname, "roleType": 0, "eloRate": 1500, "eloRank": 100, "country": null })
+	}
+	fn versus(user_1: (&str, &str), user_2: (&str, &str), wins: (u32, u32), change: i16) -> Value {
+		json!({
+			"players": [player(user_1.0, user_1.1), player(user_2.0, user_2.1)],
+			"results": {
+				"ranked": { "total": wins.0 + wins.1, user_1.0: wins.0, user_2.0: wins.1 },
+				"casual": { "total": 1, user_1.0: 1, user_2.0: 0 },
+			},
+			"changes": { user_1.0: change, user_2.0: -change },
+		})
+	}
+
+	const URL: &str = "https://api.mcsrranked.com/users";
+	let a = ("a0c06d33c69941d09b22e0c98c4233fd", "jamyreaf");
+	let b = ("af22aaab9ee74596a3578bd6345d25b5", "Priffin");
+	let c = ("bbc886da1b024739b4b80f1542e9f61d", "RED_LIME");
+	let mock = MockTransport::new()
+		.with_data(format!("{URL}/jamyreaf/versus/Priffin"), versus(a, b, (2, 1), 33))
+		.with_data(format!("{URL}/jamyreaf/versus/RED_LIME"), versus(a, c, (0, 3), -50))
+		// Players sent in the opposite order of the request
+		.with_data(format!("{URL}/Priffin/versus/RED_LIME"), versus(c, b, (1, 1), 0));
+	let client = RankedClient::builder()
+		.no_retry()
+		.transport(mock.clone())
+		.build()
+		.unwrap();
+
+	let players = ["jamyreaf", "Priffin", "RED_LIME"].map(UserIdentifier::Nickname);
+	let report = client.get_versus_matrix(&players, None).await;
+	assert!(report.is_complete());
+	let matrix = report.into_result().unwrap();
+	assert_eq!(mock.requests().len(), 3);
+	assert_eq!(matrix.len(), 3);
+	assert_eq!(matrix.record(0, 0), None);
+
+	let a_vs_b = matrix.record(0, 1).unwrap();
+	assert_eq!((a_vs_b.ranked.wins, a_vs_b.ranked.losses), (2, 1));
+	assert_eq!(a_vs_b.elo_change, 33);
+	let b_vs_a = matrix.record(1, 0).unwrap();
+	assert_eq!((b_vs_a.ranked.wins, b_vs_a.ranked.losses), (1, 2));
+	assert_eq!((b_vs_a.casual.wins, b_vs_a.casual.losses), (0, 1));
+	assert_eq!(b_vs_a.elo_change, -33);
+	assert_eq!(matrix.record(2, 0).unwrap().elo_change, 50);
+	assert_eq!(matrix.players[2].name(), "RED_LIME");
+	assert_eq!(matrix.players[1].name(), "Priffin");
+
+	let csv = matrix.to_csv();
+	let lines: Vec<_> = csv.lines().collect();
+	assert_eq!(lines.len(), 7);
+	assert!(lines[0].starts_with("player,opponent,ranked_wins"));
+	assert_eq!(lines[1], "jamyreaf,Priffin,2,1,3,1,0,1,33");
+	assert_eq!(lines[6], "RED_LIME,Priffin,1,1,2,1,0,1,0");
+
+	#[cfg(feature = "serialize")]
+	{
+		let json: Value = serde_json::from_str(&matrix.to_json().unwrap()).unwrap();
+		assert_eq!(json["records"][0][1]["ranked"]["wins"], 2);
+		assert_eq!(json["records"][1][1], Value::Null);
+	}
+
+	// A failed pair leaves the rest of the matrix
+	let mock = MockTransport::new()
+		.with_data(format!("{URL}/jamyreaf/versus/Priffin"), versus(a, b, (2, 1), 33))
+		.with_data(format!("{URL}/jamyreaf/versus/RED_LIME"), versus(a, c, (0, 3), -50));
+	let client = RankedClient::builder()
+		.no_retry()
+		.transport(mock)
+		.build()
+		.unwrap();
+	let report = client.get_versus_matrix(&players, None).await;
+	assert!(!report.is_complete());
+	assert_eq!(report.errors.len(), 1);
+	assert_eq!((report.errors[0].player, report.errors[0].opponent), (1, 2));
+	let error: &dyn std::error::Error = &report.errors[0];
+	assert!(error.to_string().starts_with("Versus request of players 1 and 2 failed: "));
+	assert!(error.source().is_some());
+	assert!(report.matrix.record(0, 2).is_some());
+	assert_eq!(report.matrix.record(1, 2), None);
+	assert_eq!(report.matrix.record(2, 1), None);
+	assert!(report.into_result().is_err());
+}
@@ -0,0 +1,322 @@
+use std::{
+	collections::{HashSet, VecDeque},
+	future::Future,
+	num::NonZeroU8,
+	pin::Pin,
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	task::{Context, Poll},
+	time::Duration,
+};
+
+use futures_util::{Stream, StreamExt, ready, task::AtomicWaker};
+use tokio::time::{Interval, MissedTickBehavior};
+
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	pagination::{Pagination, RelativePos},
+	types::{MatchId, Season},
+};
+
+use super::{MatchInfo, MatchType, requests::GetRecentMatchesParams};
+
+type PageFuture = Pin<Box<dyn Future<Output = Result<Box<[MatchInfo]>>> + Send>>;
+
+/// Amount of match ids remembered for deduplication
+const SEEN_CAPACITY: usize = 1000;
+
+/// Options of [`RankedClient::watch_recent_matches`]
+///
+/// Note: this struct supports the builder pattern
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use mcsr_ranked_api::game::{MatchType, watch::WatchOptions};
+///
+/// let options = WatchOptions::default()
+///     .interval(Duration::from_secs(5))
+///     .kind(MatchType::Ranked)
+///     .season(7);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchOptions {
+	/// Time between two polls of the recent matches
+	pub interval: Duration,
+	/// Amount of recent matches requested per page,
+	/// further pages are requested if more matches finished since the previous poll
+	pub count: NonZeroU8,
+	pub kind: Option<MatchType>,
+	pub tag: Option<Box<str>>,
+	pub season: Option<Season>,
+	pub include_decay: bool,
+	/// Whether to also yield the matches found by the first poll,
+	/// which finished before the watcher was started
+	pub emit_initial: bool,
+}
+
+impl Default for WatchOptions {
+	fn default() -> Self {
+		Self {
+			interval: Duration::from_secs(10),
+			count: const { NonZeroU8::new(100).unwrap() },
+			kind: None,
+			tag: None,
+			season: None,
+			include_decay: false,
+			emit_initial: false,
+		}
+	}
+}
+
+impl WatchOptions {
+	/// Set the `interval` field
+	pub fn interval(mut self, interval: Duration) -> Self {
+		self.interval = interval;
+		self
+	}
+	/// Set the `count` field, `None` if it is not between 1 and 100
+	pub fn count(mut self, count: u8) -> Option<Self> {
+		self.count = Pagination::count(count)?.count;
+		Some(self)
+	}
+	/// Set the `kind` field
+	pub fn kind(mut self, kind: MatchType) -> Self {
+		self.kind = Some(kind);
+		self
+	}
+	/// Set the `tag` field
+	pub fn tag(mut self, tag: impl Into<Box<str>>) -> Self {
+		self.tag = Some(tag.into());
+		self
+	}
+	/// Set the `season` field
+	pub fn season(mut self, season: Season) -> Self {
+		self.season = Some(season);
+		self
+	}
+	/// Set the `include_decay` field
+	pub fn include_decay(mut self, include_decay: bool) -> Self {
+		self.include_decay = include_decay;
+		self
+	}
+	/// Set the `emit_initial` field
+	pub fn emit_initial(mut self, emit_initial: bool) -> Self {
+		self.emit_initial = emit_initial;
+		self
+	}
+}
+
+#[derive(Debug, Default)]
+struct Shutdown {
+	triggered: AtomicBool,
+	waker: AtomicWaker,
+}
+
+/// Handle to stop a [`MatchWatcher`] from another task
+#[derive(Debug, Clone)]
+pub struct WatchHandle(Arc<Shutdown>);
+
+impl WatchHandle {
+	/// Stop the watcher
+	///
+	/// A poll in flight is completed and the matches found so far are still yielded,
+	/// then the stream ends
+	pub fn shutdown(&self) {
+		self.0.triggered.store(true, Ordering::Release);
+		self.0.waker.wake();
+	}
+	/// Whether [`WatchHandle::shutdown`] was called
+	pub fn is_shutdown(&self) -> bool {
+		self.0.triggered.load(Ordering::Acquire)
+	}
+}
+
+/// Match ids seen recently, forgetting the oldest ones
+#[derive(Debug, Default)]
+struct SeenIds {
+	ids: HashSet<MatchId>,
+	order: VecDeque<MatchId>,
+}
+
+impl SeenIds {
+	/// Remember the `id`, returning whether it is new
+	fn insert(&mut self, id: MatchId) -> bool {
+		if !self.ids.insert(id) {
+			return false;
+		}
+		self.order.push_back(id);
+		if self.order.len() > SEEN_CAPACITY
+			&& let Some(oldest) = self.order.pop_front()
+		{
+			self.ids.remove(&oldest);
+		}
+		true
+	}
+}
+
+/// Stream of newly finished matches, oldest first
+///
+/// Created by [`RankedClient::watch_recent_matches`].
+/// The recent matches are polled on an interval and every match is yielded once.
+/// The next poll only happens after the matches of the previous one were consumed,
+/// so a slow consumer delays the polls instead of piling up matches.
+/// If a whole page finished since the previous poll, older pages are requested
+/// until a match seen before is reached, so no match is skipped between two polls.
+/// Errors are yielded without ending the stream, polling continues on the next tick.
+/// Must be polled within a Tokio runtime with the time driver enabled.
+pub struct MatchWatcher {
+	client: RankedClient,
+	options: WatchOptions,
+	/// Created on the first poll, as it requires a runtime
+	interval: Option<Interval>,
+	shutdown: Arc<Shutdown>,
+	seen: SeenIds,
+	/// Id of the newest match seen so far
+	latest: Option<MatchId>,
+	primed: bool,
+	buffer: VecDeque<MatchInfo>,
+	pending: Option<PageFuture>,
+}
+
+impl MatchWatcher {
+	fn new(client: RankedClient, options: WatchOptions) -> Self {
+		Self {
+			client,
+			options,
+			interval: None,
+			shutdown: Default::default(),
+			seen: Default::default(),
+			latest: None,
+			primed: false,
+			buffer: VecDeque::new(),
+			pending: None,
+		}
+	}
+
+	/// Handle to stop the watcher, see [`WatchHandle::shutdown`]
+	pub fn handle(&self) -> WatchHandle {
+		WatchHandle(self.shutdown.clone())
+	}
+
+	/// Call `callback` with every new match (or error) until the watcher is stopped
+	///
+	/// The next match is only taken after the future returned by `callback` finished
+	pub async fn run<F, Fut>(mut self, mut callback: F)
+	where
+		F: FnMut(Result<MatchInfo>) -> Fut,
+		Fut: Future<Output = ()>,
+	{
+		while let Some(item) = self.next().await {
+			callback(item).await;
+		}
+	}
+
+	fn fetch(&self) -> PageFuture {
+		let client = self.client.clone();
+		let options = self.options.clone();
+		let latest = self.latest;
+		Box::pin(async move {
+			let mut params = GetRecentMatchesParams {
+				pagination: Pagination {
+					count: options.count,
+					..Default::default()
+				},
+				kind: options.kind,
+				tag: options.tag.as_deref(),
+				season: options.season,
+				include_decay: options.include_decay,
+			};
+			let mut matches = Vec::new();
+			loop {
+				let page = client.get_recent_matches(&params).await?;
+				let full = page.len() >= usize::from(options.count.get());
+				let oldest = page.last().map(|info| info.id);
+				matches.extend(page);
+				// Page back while the whole page finished after the newest seen match
+				match (latest, oldest) {
+					(Some(latest), Some(oldest)) if full && oldest > latest => {
+						params.pagination.position = RelativePos::before(oldest);
+					}
+					_ => return Ok(matches.into()),
+				}
+			}
+		})
+	}
+
+	fn accept(&mut self, page: Box<[MatchInfo]>) {
+		let emit = self.primed || self.options.emit_initial;
+		self.primed = true;
+		// The page is newest first
+		for info in page.into_iter().rev() {
+			self.latest = self.latest.max(Some(info.id));
+			if self.seen.insert(info.id) && emit {
+				self.buffer.push_back(info);
+			}
+		}
+	}
+}
+
+impl Stream for MatchWatcher {
+	type Item = Result<MatchInfo>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		loop {
+			if let Some(info) = this.buffer.pop_front() {
+				return Poll::Ready(Some(Ok(info)));
+			}
+			if this.pending.is_none() {
+				this.shutdown.waker.register(cx.waker());
+				if this.shutdown.triggered.load(Ordering::Acquire) {
+					return Poll::Ready(None);
+				}
+				let period = this.options.interval.max(Duration::from_millis(1));
+				let interval = this.interval.get_or_insert_with(|| {
+					let mut interval = tokio::time::interval(period);
+					interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+					interval
+				});
+				ready!(interval.poll_tick(cx));
+				this.pending = Some(this.fetch());
+			}
+			let pending = this.pending.as_mut().expect("Expected a poll in flight");
+			let result = ready!(pending.as_mut().poll(cx));
+			this.pending = None;
+			match result {
+				Ok(page) => this.accept(page),
+				Err(err) => return Poll::Ready(Some(Err(err))),
+			}
+		}
+	}
+}
+
+impl std::fmt::Debug for MatchWatcher {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MatchWatcher")
+			.field("client", &self.client)
+			.field("options", &self.options)
+			.field("buffered", &self.buffer.len())
+			.field("pending", &self.pending.is_some())
+			.finish()
+	}
+}
+
+impl RankedClient {
+	/// Watch the recent matches for newly finished ones, see [`MatchWatcher`]
+	///
+	/// The pages are requested without the client's response cache
+	pub fn watch_recent_matches(&self, options: WatchOptions) -> MatchWatcher {
+		MatchWatcher::new(self.without_cache(), options)
+	}
+}
+
+impl MatchInfo {
+	/// Watch the recent matches for newly finished ones, see [`MatchWatcher`]
+	pub fn watch_recent(options: WatchOptions) -> MatchWatcher {
+		default_client().watch_recent_matches(options)
+	}
+}
@@ -1,6 +1,13 @@
-use serde::{de::DeserializeOwned, Serialize};
+use reqwest::StatusCode;
+use serde::{Serialize, de::DeserializeOwned};
 
-use crate::{types::DeResult, Result};
+use crate::{
+	Error,
+	types::{DeResult, DecodeError},
+};
+
+/// Amount of bytes of the body kept on each side of a decoding error
+const EXCERPT_RADIUS: usize = 80;
 
 #[cfg(test)]
 mod tests;
@@ -8,6 +15,7 @@ mod tests;
 #[cfg(all(feature = "serialize", feature = "variations"))]
 pub(crate) mod ser_str;
 
+#[cfg(feature = "weekly_races")]
 pub(crate) mod string_u64 {
 	use serde::Deserialize;
 	#[cfg(feature = "serialize")]
@@ -31,7 +39,81 @@ pub(crate) mod string_u64 {
 	}
 }
 
-fn construct_url<'v, V, S>(
+/// Implement `u8` (de)serialization for an enum with an `Unknown(u8)` fallback variant
+///
+/// Unknown values deserialize into the fallback, or fail with the `strict` feature.
+/// Serialization requires the `serialize` feature, unless the enum is marked with `query`
+/// as it is sent in the query parameters of requests
+macro_rules! repr_enum {
+	(query $name:ident { $($variant:ident = $value:literal),* $(,)? }) => {
+		$crate::helpers::repr_enum!(@impl $name { $($variant = $value),* });
+		$crate::helpers::repr_enum!(@serialize $name);
+	};
+	($name:ident { $($variant:ident = $value:literal),* $(,)? }) => {
+		$crate::helpers::repr_enum!(@impl $name { $($variant = $value),* });
+		#[cfg(feature = "serialize")]
+		$crate::helpers::repr_enum!(@serialize $name);
+	};
+	(@impl $name:ident { $($variant:ident = $value:literal),* }) => {
+		impl $name {
+			/// The raw value used by the API
+			pub fn value(&self) -> u8 {
+				match self {
+					$(Self::$variant => $value,)*
+					Self::Unknown(value) => *value,
+				}
+			}
+		}
+
+		impl From<u8> for $name {
+			fn from(value: u8) -> Self {
+				match value {
+					$($value => Self::$variant,)*
+					_ => Self::Unknown(value),
+				}
+			}
+		}
+
+		impl<'de> serde::Deserialize<'de> for $name {
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				let value = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+				#[cfg(feature = "strict")]
+				if let Self::Unknown(value) = Self::from(value) {
+					return Err(serde::de::Error::invalid_value(
+						serde::de::Unexpected::Unsigned(value.into()),
+						&concat!("a known ", stringify!($name)),
+					));
+				}
+				Ok(Self::from(value))
+			}
+		}
+	};
+	(@serialize $name:ident) => {
+		impl serde::Serialize for $name {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: serde::Serializer,
+			{
+				serializer.serialize_u8(self.value())
+			}
+		}
+	};
+}
+pub(crate) use repr_enum;
+
+/// Join the API `base` URL and an endpoint `path`
+pub(crate) fn join_url(base: &str, path: &str) -> String {
+	let mut url = String::with_capacity(base.len() + path.len() + 1);
+	url.push_str(base.trim_end_matches('/'));
+	url.push('/');
+	url.push_str(path.trim_start_matches('/'));
+	url
+}
+
+pub(crate) fn construct_url<'v, V, S>(
 	base: impl ToString,
 	variables: V,
 	params: Option<&impl Serialize>,
@@ -54,37 +136,52 @@ where
 	url.into_boxed_str()
 }
 
-pub(crate) async fn make_request<'v, T, V, S>(
-	base_url: &str,
-	variables: V,
-	params: Option<&impl Serialize>,
-) -> Result<T>
+/// Decode the response `body` of the request to `url`
+pub(crate) fn decode<T>(url: &str, status: StatusCode, body: &[u8]) -> Result<DeResult<T>, Error>
 where
 	T: DeserializeOwned,
-	V: IntoIterator<Item = &'v S>,
-	S: AsRef<str> + 'v,
 {
-	let url = construct_url(base_url, variables, params);
-	reqwest::get(url.as_ref())
-		.await?
-		.json::<DeResult<T>>()
-		.await?
-		.into()
+	let deserializer = &mut serde_json::Deserializer::from_slice(body);
+	serde_path_to_error::deserialize(deserializer).map_err(|err| {
+		let path = err.path().to_string();
+		let inner = err.into_inner();
+		Error::Decode(DecodeError {
+			url: url.into(),
+			status,
+			path: path.into(),
+			body_excerpt: excerpt(body, inner.line(), inner.column()).into(),
+			message: inner.to_string().into(),
+		})
+	})
 }
 
-#[cfg(feature = "blocking")]
-pub(crate) fn make_request_blocking<'v, T, V, S>(
-	base_url: &str,
-	variables: V,
-	params: Option<&impl Serialize>,
-) -> Result<T>
-where
-	T: DeserializeOwned,
-	V: IntoIterator<Item = &'v S>,
-	S: AsRef<str> + 'v,
-{
-	let url = construct_url(base_url, variables, params);
-	reqwest::blocking::get(url.as_ref())?
-		.json::<DeResult<T>>()?
-		.into()
+/// Part of the `body` around the 1-indexed `line` and `column`
+fn excerpt(body: &[u8], line: usize, column: usize) -> String {
+	let body = String::from_utf8_lossy(body);
+	let offset = body
+		.split_inclusive('\n')
+		.take(line.saturating_sub(1))
+		.map(str::len)
+		.sum::<usize>()
+		+ column;
+	let offset = offset.min(body.len());
+
+	let mut start = offset.saturating_sub(EXCERPT_RADIUS);
+	while !body.is_char_boundary(start) {
+		start -= 1;
+	}
+	let mut end = (offset + EXCERPT_RADIUS).min(body.len());
+	while !body.is_char_boundary(end) {
+		end += 1;
+	}
+
+	let mut excerpt = String::with_capacity(end - start + 6);
+	if start > 0 {
+		excerpt.push_str("...");
+	}
+	excerpt.push_str(&body[start..end]);
+	if end < body.len() {
+		excerpt.push_str("...");
+	}
+	excerpt
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::helpers::construct_url;
+use crate::helpers::{construct_url, join_url};
 
 #[test]
 fn construct() {
@@ -24,3 +24,42 @@ fn construct() {
 		r#"http://example.com/user/test_user?age=19&name=laysar"#
 	);
 }
+
+#[test]
+fn join() {
+	assert_eq!(
+		join_url("https://api.mcsrranked.com", "users/{}"),
+		"https://api.mcsrranked.com/users/{}"
+	);
+	assert_eq!(
+		join_url("http://localhost:8080/mirror/", "/leaderboard"),
+		"http://localhost:8080/mirror/leaderboard"
+	);
+}
+
+#[test]
+fn decode_error() {
+	use reqwest::StatusCode;
+
+	use crate::{Error, helpers::decode, user::UserProfile};
+
+	const JSON: &str = r#"{"status":"success","data":[{"uuid":"79635c3dbf634a228bf44544cc7c0d27","nickname":"LaysarOwO","roleType":0,"eloRate":1226,"eloRank":333,"country":null},{"uuid":"3c8757790ab0400b8b9e3936e0dd535b","nickname":"doogile","roleType":"9","eloRate":1804,"eloRank":33,"country":"us"}]}"#;
+	const URL: &str = "https://api.mcsrranked.com/test";
+
+	let Err(Error::Decode(error)) =
+		decode::<Box<[UserProfile]>>(URL, StatusCode::OK, JSON.as_bytes())
+	else {
+		panic!("Expected a decode error");
+	};
+	assert_eq!(error.url.as_ref(), URL);
+	assert_eq!(error.status, StatusCode::OK);
+	assert_eq!(error.path.as_ref(), "data[1].roleType");
+	assert!(error.body_excerpt.contains(r#""roleType":"9""#));
+	assert!(error.body_excerpt.starts_with("..."));
+	assert!(error.body_excerpt.len() < JSON.len());
+
+	assert!(
+		decode::<Box<[UserProfile]>>(URL, StatusCode::OK, br#"{"status":"success","data":[]}"#)
+			.is_ok()
+	);
+}
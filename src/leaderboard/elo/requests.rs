@@ -1,15 +1,40 @@
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
-use crate::{Result, helpers::make_request, leaderboard::requests::GetLeaderboardInfoParams};
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	leaderboard::requests::GetLeaderboardInfoParams,
+};
 
 use super::EloLeaderboardInfo;
 
-const BASE_URL: &str = "https://api.mcsrranked.com/leaderboard";
+const BASE_PATH: &str = "leaderboard";
+
+impl RankedClient {
+	/// GET the user leaderboard using given `params`
+	pub async fn get_elo_leaderboard<'a>(
+		&self,
+		params: impl Into<Option<&'a GetLeaderboardInfoParams>>,
+	) -> Result<EloLeaderboardInfo> {
+		self.request(BASE_PATH, &[] as &[&str], params.into()).await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the user leaderboard using given `params`
+	pub fn get_elo_leaderboard<'a>(
+		&self,
+		params: impl Into<Option<&'a GetLeaderboardInfoParams>>,
+	) -> Result<EloLeaderboardInfo> {
+		self.request(BASE_PATH, &[] as &[&str], params.into())
+	}
+}
 
 impl EloLeaderboardInfo {
 	/// GET the user leaderboard using given `params`
 	pub async fn get<'a>(params: impl Into<Option<&'a GetLeaderboardInfoParams>>) -> Result<Self> {
-		make_request(BASE_URL, &[] as &[&str], params.into()).await
+		default_client().get_elo_leaderboard(params).await
 	}
 }
 
@@ -19,6 +44,6 @@ impl EloLeaderboardInfo {
 	pub fn get_blocking<'a>(
 		params: impl Into<Option<&'a GetLeaderboardInfoParams>>,
 	) -> Result<Self> {
-		make_request_blocking(BASE_URL, &[] as &[&str], params.into())
+		default_blocking_client().get_elo_leaderboard(params)
 	}
 }
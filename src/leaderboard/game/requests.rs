@@ -3,12 +3,16 @@ use std::{num::NonZero, ops::Not};
 use serde::Serialize;
 
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
-use crate::{Result, helpers::make_request, types::Season};
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	types::Season,
+};
 
 use super::BestTimeLeaderboard;
 
-const BASE_URL: &str = "https://api.mcsrranked.com/record-leaderboard";
+const BASE_PATH: &str = "record-leaderboard";
 
 /// Season(s) parameter to fetch best times
 ///
@@ -116,12 +120,33 @@ impl GetBestTimeLeaderboardParams {
 	}
 }
 
+impl RankedClient {
+	/// GET the best time leaderboard using given `params`
+	pub async fn get_best_time_leaderboard<'a>(
+		&self,
+		params: impl Into<Option<&'a GetBestTimeLeaderboardParams>>,
+	) -> Result<BestTimeLeaderboard> {
+		self.request(BASE_PATH, &[] as &[&str], params.into()).await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the best time leaderboard using given `params`
+	pub fn get_best_time_leaderboard<'a>(
+		&self,
+		params: impl Into<Option<&'a GetBestTimeLeaderboardParams>>,
+	) -> Result<BestTimeLeaderboard> {
+		self.request(BASE_PATH, &[] as &[&str], params.into())
+	}
+}
+
 impl BestTimeLeaderboard {
 	/// GET the best time leaderboard using given `params`
 	pub async fn get<'a>(
 		params: impl Into<Option<&'a GetBestTimeLeaderboardParams>>,
 	) -> Result<Self> {
-		make_request(BASE_URL, &[] as &[&str], params.into()).await
+		default_client().get_best_time_leaderboard(params).await
 	}
 }
 
@@ -131,6 +156,6 @@ impl BestTimeLeaderboard {
 	pub fn get_blocking<'a>(
 		params: impl Into<Option<&'a GetBestTimeLeaderboardParams>>,
 	) -> Result<Self> {
-		make_request_blocking(BASE_URL, &[] as &[&str], params.into())
+		default_blocking_client().get_best_time_leaderboard(params)
 	}
 }
@@ -1,11 +1,13 @@
 #[cfg(feature = "achievements")]
 pub mod achievement;
+pub mod client;
 #[cfg(feature = "matches")]
 pub mod game;
 pub(crate) mod helpers;
 #[cfg(feature = "leaderboards")]
 pub mod leaderboard;
 pub mod pagination;
+pub mod rating;
 pub mod types;
 pub mod user;
 #[cfg(feature = "variations")]
@@ -16,5 +18,11 @@ pub mod weekly_race;
 #[cfg(all(test, feature = "blocking"))]
 pub mod request_tests;
 
+pub use client::RankedClient;
+#[cfg(feature = "blocking")]
+pub use client::blocking::BlockingRankedClient;
+pub use types::ApiError;
+pub use types::ApiErrorKind;
+pub use types::DecodeError;
 pub use types::Error;
 pub use types::Result;
@@ -105,9 +105,9 @@ impl Error for RelativePosError {}
 #[serde(rename_all = "lowercase")]
 pub struct RelativePos {
 	#[serde(skip_serializing_if = "Option::is_none")]
-	before: Option<MatchId>,
+	pub(crate) before: Option<MatchId>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	after: Option<MatchId>,
+	pub(crate) after: Option<MatchId>,
 }
 impl RelativePos {
 	/// Creates a new [`RelativePos`] after checking whether [`before`, `after`] is a valid range.
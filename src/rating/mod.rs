@@ -0,0 +1,160 @@
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+#[cfg(feature = "matches")]
+use crate::game::{
+	MatchInfo,
+	perspective::{MatchPerspective, PlayerOutcome},
+};
+use crate::types::{Elo, EloChange};
+
+#[cfg(test)]
+mod tests;
+
+/// Estimated K-factor of ranked matches
+///
+/// An approximation of the recorded elo updates, not a value published by the API.
+/// Use [`EloModel::fit`] to estimate it from other matches
+pub const DEFAULT_K_FACTOR: f64 = 38.0;
+/// Elo difference at which the stronger player is expected to score 10 times as much
+pub const DEFAULT_SCALE: f64 = 400.0;
+
+/// Predicted elo changes of a player for every outcome of a match
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloPrediction {
+	/// Expected score of the player, between 0 (certain loss) and 1 (certain win)
+	pub expected_score: f64,
+	pub win: EloChange,
+	pub draw: EloChange,
+	pub loss: EloChange,
+}
+
+/// Elo rating model used to predict the changes of ranked matches
+///
+/// Note: this struct supports the builder pattern
+///
+/// # Examples
+/// ```
+/// use mcsr_ranked_api::rating::EloModel;
+///
+/// let model = EloModel::default();
+/// let prediction = model.predict(1650, 1880);
+/// assert_eq!(prediction.win, 30);
+/// assert_eq!(prediction.loss, -8);
+/// ```
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloModel {
+	pub k_factor: f64,
+	pub scale: f64,
+}
+
+impl Default for EloModel {
+	fn default() -> Self {
+		Self {
+			k_factor: DEFAULT_K_FACTOR,
+			scale: DEFAULT_SCALE,
+		}
+	}
+}
+
+impl EloModel {
+	/// Set the `k_factor` field
+	pub fn k_factor(mut self, k_factor: f64) -> Self {
+		self.k_factor = k_factor;
+		self
+	}
+	/// Set the `scale` field
+	pub fn scale(mut self, scale: f64) -> Self {
+		self.scale = scale;
+		self
+	}
+
+	/// Expected score of a player with `elo` against an `opponent`
+	pub fn expected_score(&self, elo: Elo, opponent: Elo) -> f64 {
+		let difference = f64::from(opponent) - f64::from(elo);
+		1.0 / (1.0 + 10f64.powf(difference / self.scale))
+	}
+
+	/// Probability of a player with `elo` beating an `opponent`
+	///
+	/// Draws are rare enough in ranked to be ignored, so this equals the expected score
+	pub fn win_probability(&self, elo: Elo, opponent: Elo) -> f64 {
+		self.expected_score(elo, opponent)
+	}
+
+	/// Elo change of a player with `elo` scoring `score` against an `opponent`,
+	/// where a win scores 1, a draw 0.5 and a loss 0
+	pub fn change(&self, elo: Elo, opponent: Elo, score: f64) -> EloChange {
+		let change = self.k_factor * (score - self.expected_score(elo, opponent));
+		change
+			.round()
+			.clamp(EloChange::MIN.into(), EloChange::MAX.into()) as EloChange
+	}
+
+	/// Elo changes of a player with `elo` against an `opponent` for every outcome
+	pub fn predict(&self, elo: Elo, opponent: Elo) -> EloPrediction {
+		EloPrediction {
+			expected_score: self.expected_score(elo, opponent),
+			win: self.change(elo, opponent, 1.0),
+			draw: self.change(elo, opponent, 0.5),
+			loss: self.change(elo, opponent, 0.0),
+		}
+	}
+}
+
+#[cfg(feature = "matches")]
+impl EloModel {
+	/// Predicted elo change of the player of a recorded match
+	///
+	/// The elos before the match are those of [`MatchPerspective::elo_before`].
+	/// `None` if the elo of the player or their opponent before the match is unknown
+	pub fn predict_match(&self, perspective: &MatchPerspective) -> Option<EloChange> {
+		let (elo, opponent) = elos_before(perspective)?;
+		Some(self.change(elo, opponent, score(perspective.outcome())))
+	}
+
+	/// Fit the K-factor to the elo updates of recorded `matches` using least squares
+	///
+	/// Decayed matches and matches without known elo changes are skipped,
+	/// `None` if no match could be used
+	pub fn fit<'a>(mut self, matches: impl IntoIterator<Item = &'a MatchInfo>) -> Option<Self> {
+		let (mut numerator, mut denominator) = (0.0, 0.0);
+		for info in matches.into_iter().filter(|info| !info.decayed) {
+			let Some(perspective) = info.players.first().and_then(|p| info.perspective(p.uuid))
+			else {
+				continue;
+			};
+			let (Some((elo, opponent)), Some(change)) =
+				(elos_before(&perspective), perspective.elo_change())
+			else {
+				continue;
+			};
+			let surprise = score(perspective.outcome()) - self.expected_score(elo, opponent);
+			numerator += f64::from(change) * surprise;
+			denominator += surprise * surprise;
+		}
+		(denominator > 0.0).then(|| {
+			self.k_factor = numerator / denominator;
+			self
+		})
+	}
+}
+
+#[cfg(feature = "matches")]
+fn elos_before(perspective: &MatchPerspective) -> Option<(Elo, Elo)> {
+	Some((
+		perspective.elo_before()?,
+		perspective.opponent_elo_before()?,
+	))
+}
+
+#[cfg(feature = "matches")]
+fn score(outcome: PlayerOutcome) -> f64 {
+	match outcome {
+		PlayerOutcome::Win => 1.0,
+		PlayerOutcome::Draw => 0.5,
+		PlayerOutcome::Loss => 0.0,
+	}
+}
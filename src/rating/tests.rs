@@ -0,0 +1,117 @@
+#[cfg(feature = "matches")]
+use super::DEFAULT_K_FACTOR;
+use super::EloModel;
+
+#[test]
+fn expected_score() {
+	let model = EloModel::default();
+	assert_eq!(model.expected_score(1500, 1500), 0.5);
+	assert!((model.expected_score(1900, 1500) - 10.0 / 11.0).abs() < 1e-9);
+	assert!((model.expected_score(1500, 1900) - 1.0 / 11.0).abs() < 1e-9);
+	assert_eq!(
+		model.win_probability(1200, 1300) + model.win_probability(1300, 1200),
+		1.0
+	);
+}
+
+#[test]
+fn predict() {
+	let model = EloModel::default();
+	let even = model.predict(1500, 1500);
+	assert_eq!((even.win, even.draw, even.loss), (19, 0, -19));
+
+	let favourite = model.predict(1900, 1500);
+	assert_eq!(
+		(favourite.win, favourite.draw, favourite.loss),
+		(3, -16, -35)
+	);
+
+	let custom = EloModel::default().k_factor(20.0).predict(1500, 1500);
+	assert_eq!(custom.win, 10);
+}
+
+#[cfg(feature = "matches")]
+fn fixture_matches(name: &str) -> Vec<crate::game::MatchInfo> {
+	let path = format!("{}/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+	let fixture: serde_json::Value =
+		serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+	serde_json::from_value(fixture["body"]["data"].clone()).unwrap()
+}
+
+#[cfg(feature = "matches")]
+fn recorded() -> Vec<crate::game::MatchInfo> {
+	[
+		"matches.json",
+		"users_3c875779-0ab0-400b-8b9e-3936e0dd535b_matches-count=100-season=6-exclude_decay=false.json",
+		"users_79635c3d-bf63-4a22-8bf4-4544cc7c0d27_matches.json",
+		"users_jamyreaf_versus_Priffin_matches.json",
+	]
+	.into_iter()
+	.flat_map(fixture_matches)
+	.collect()
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn recorded_matches() {
+	use crate::game::perspective::PlayerOutcome;
+
+	let matches = recorded();
+	let model = EloModel::default();
+	let (mut uneven, mut upsets) = (0, 0);
+	for info in &matches {
+		for player in info.players() {
+			let perspective = info.perspective(player.uuid).unwrap();
+			assert_eq!(
+				model.predict_match(&perspective),
+				perspective.elo_change(),
+				"match {}, player {}",
+				info.id,
+				player.nickname
+			);
+
+			let elo = perspective.elo_before().unwrap();
+			let opponent = perspective.opponent_elo_before().unwrap();
+			uneven += usize::from(elo.abs_diff(opponent) >= 100);
+			upsets += usize::from(perspective.outcome() == PlayerOutcome::Win && elo < opponent);
+		}
+	}
+	assert!(uneven >= 8 && upsets >= 3);
+
+	let fitted = EloModel::default().fit(&matches).unwrap();
+	assert!((fitted.k_factor - DEFAULT_K_FACTOR).abs() < 0.5);
+	assert_eq!(EloModel::default().fit([]), None);
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn fit_k_factor() {
+	use crate::{game::MatchEloUpdate, types::Elo};
+
+	// Replace the recorded elo changes by those of a lower K-factor
+	let model = EloModel::default().k_factor(24.0);
+	let matches: Vec<_> = recorded()
+		.into_iter()
+		.map(|mut info| {
+			let updates: Vec<_> = info
+				.players()
+				.iter()
+				.map(|player| {
+					let perspective = info.perspective(player.uuid).unwrap();
+					let before = perspective.elo_before().unwrap();
+					let change = model.predict_match(&perspective).unwrap();
+					MatchEloUpdate {
+						player_uuid: player.uuid,
+						change: Some(change),
+						elo: Elo::try_from(i32::from(before) + i32::from(change)).ok(),
+					}
+				})
+				.collect();
+			info.elo_updates = updates.into();
+			info
+		})
+		.collect();
+
+	let fitted = EloModel::default().fit(&matches).unwrap();
+	assert!((fitted.k_factor - 24.0).abs() < 0.5);
+}
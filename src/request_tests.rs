@@ -1,32 +1,223 @@
-use crate::{game::requests::GetMatchesParams, pagination::Pagination};
+//! Replay tests of every endpoint
+//!
+//! The responses are replayed from the fixtures in the `fixtures` directory.
+//! The fixtures are synthetic, see `fixtures/README.md`, so these tests check the requests
+//! and decoding against plausible responses rather than the live API.
+//! Every request has its own fixture, whose content matches the request's parameters.
+//! Run with `MCSR_RANKED_CASSETTE=record` to replace them with recordings from the API
 
-#[test]
-#[ignore]
-fn user_redlime() {
-	use uuid::uuid;
+use uuid::uuid;
+
+use crate::{
+	client::{Cassette, blocking::BlockingRankedClient},
+	user::{identifier::UserIdentifier, info::UserInfo},
+};
 
-	use crate::user::identifier::UserIdentifier;
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
 
+fn client() -> BlockingRankedClient {
+	BlockingRankedClient::builder()
+		.transport(Cassette::from_env(
+			FIXTURES,
+			reqwest::blocking::Client::new(),
+		))
+		.no_retry()
+		.build()
+		.expect("Expected a valid client configuration")
+}
+
+#[test]
+fn user_redlime() {
 	let redlime_id = UserIdentifier::Nickname("RED_LIME");
-	let request = redlime_id.get_user_blocking(None);
-	let redlime = request.unwrap();
+	let redlime = client().get_user(&redlime_id, None).unwrap();
 	assert_eq!(
 		redlime.profile.uuid,
-		uuid!("bbc886da1b024739b4b80f1542e9f61d"),
-		"Check your internet connection"
+		uuid!("bbc886da1b024739b4b80f1542e9f61d")
+	);
+}
+
+#[test]
+fn user_raw() {
+	let client = client();
+	let (redlime, raw) = client
+		.get_with_raw::<UserInfo>("users/RED_LIME", None::<&()>)
+		.unwrap()
+		.into_parts();
+	assert_eq!(raw["nickname"], *redlime.profile.nickname);
+	assert!(raw["connections"].is_object());
+	assert_eq!(
+		redlime,
+		client
+			.get_user(&UserIdentifier::Nickname("RED_LIME"), None)
+			.unwrap()
 	);
+
+	let data = client.get_raw("users/RED_LIME", None::<&()>).unwrap();
+	assert_eq!(data, raw);
+}
+
+#[test]
+fn user_all_seasons() {
+	let feinberg_id = UserIdentifier::Nickname("Feinberg");
+	let feinberg = client().get_user_all_seasons(&feinberg_id).unwrap();
+	assert_eq!(feinberg.profile.nickname.as_ref(), "Feinberg");
+	assert!(feinberg.result(7).is_some());
 }
 
+#[cfg(feature = "matches")]
 #[test]
-#[ignore]
 fn user_matches() {
-	use uuid::uuid;
+	use crate::{game::requests::GetMatchesParams, pagination::Pagination};
+
+	let doogile = uuid!("3c8757790ab0400b8b9e3936e0dd535b");
+	let params = GetMatchesParams {
+		season: Some(6),
+		..Pagination::count(100).unwrap().into()
+	};
+	let matches = client()
+		.get_user_matches(&UserIdentifier::Uuid(doogile), &params)
+		.unwrap();
+	assert_eq!(matches.len(), 3);
+	assert!(matches.is_sorted_by(|a, b| a.id > b.id));
+	for info in &matches {
+		assert_eq!(info.season, 6);
+		assert!(info.players.iter().any(|player| player.uuid == doogile));
+	}
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn match_info() {
+	let info = client().get_match(1524115).unwrap();
+	assert_eq!(info.info.id, 1524115);
+	assert!(!info.timeline_events.is_empty());
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn recent_matches() {
+	let matches = client().get_recent_matches(None).unwrap();
+	let ids: Vec<_> = matches.iter().map(|info| info.id).collect();
+	assert_eq!(ids, [1530412, 1530388, 1530301]);
+	assert!(matches.is_sorted_by(|a, b| a.date >= b.date));
+}
 
-	use crate::user::identifier::UserIdentifier;
+#[cfg(feature = "matches")]
+#[test]
+fn versus() {
+	let jamyreaf_id = UserIdentifier::Nickname("jamyreaf");
+	let priffin_id = UserIdentifier::Nickname("Priffin");
+	let versus = client()
+		.get_versus(&jamyreaf_id, &priffin_id, None)
+		.unwrap();
+	assert_eq!(versus.players.0.nickname.as_ref(), "jamyreaf");
+	assert_eq!(versus.players.1.nickname.as_ref(), "Priffin");
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn versus_matches() {
+	use crate::{game::requests::GetMatchesParams, pagination::Pagination};
+
+	let jamyreaf_id = UserIdentifier::Nickname("jamyreaf");
+	let priffin_id = UserIdentifier::Nickname("Priffin");
+	let client = client();
+	let matches = client
+		.get_versus_matches(&jamyreaf_id, &priffin_id, None)
+		.unwrap();
+	assert_eq!(matches.len(), 2);
+	for info in &matches {
+		let mut nicknames: Vec<_> = info.players.iter().map(|p| p.nickname.as_ref()).collect();
+		nicknames.sort_unstable();
+		assert_eq!(nicknames, ["Priffin", "jamyreaf"]);
+	}
+
+	// One match per page, each page starting before the last match of the previous one
+	let params = GetMatchesParams::from(Pagination::count(1).unwrap());
+	let all = client
+		.iter_versus_matches(&jamyreaf_id, &priffin_id, &params)
+		.collect::<crate::Result<Vec<_>>>()
+		.unwrap();
+	assert_eq!(all, matches);
+}
+
+#[cfg(feature = "leaderboards")]
+#[test]
+fn leaderboards() {
+	let client = client();
+	let elo = client.get_elo_leaderboard(None).unwrap();
+	assert_eq!(elo.season.number, 4);
+	assert!(!elo.users.is_empty());
+	assert!(elo.users.is_sorted_by_key(|user| user.profile.rank));
+
+	let phase = client.get_phase_leaderboard(None).unwrap();
+	assert_eq!(phase.phase.season, 4);
+	assert!(!phase.users.is_empty());
+
+	let records = client.get_best_time_leaderboard(None).unwrap();
+	assert!(!records.is_empty());
+	assert!(records.is_sorted_by_key(|record| (record.rank, record.time)));
+}
+
+#[cfg(feature = "weekly_races")]
+#[test]
+fn weekly_races() {
+	let client = client();
+	let race = client.get_weekly_race(13).unwrap();
+	assert_eq!(race.id, 13);
+	assert!(race.leaderboard().is_sorted_by_key(|record| record.rank));
+
+	let current = client.get_current_weekly_race().unwrap();
+	assert_eq!(current.id, 14);
+	assert!(current.ends_at > race.ends_at);
+	assert!(current.leaderboard().is_sorted_by_key(|record| record.time));
+}
+
+#[cfg(feature = "matches")]
+#[test]
+fn reused_client() {
+	let client = client();
+	let laysar = uuid!("79635c3dbf634a228bf44544cc7c0d27");
+	let laysar_id = UserIdentifier::Uuid(laysar);
+	let user = client.get_user(&laysar_id, None).unwrap();
+	assert_eq!(user.profile.uuid, laysar);
+	let matches = client.get_user_matches(&laysar_id, None).unwrap();
+	assert_eq!(matches.len(), 2);
+	for info in &matches {
+		assert!(info.players.iter().any(|player| player.uuid == laysar));
+	}
+}
+
+#[cfg(feature = "weekly_races")]
+#[tokio::test]
+async fn async_client() {
+	use crate::RankedClient;
+
+	let client = RankedClient::builder()
+		.transport(Cassette::from_env(FIXTURES, reqwest::Client::new()))
+		.no_retry()
+		.build()
+		.unwrap();
+	let race = client.get_weekly_race(13).await.unwrap();
+	assert_eq!(race.id, 13);
+}
+
+#[test]
+fn missing_fixture() {
+	use crate::{
+		Error,
+		client::{CassetteMode, blocking::BlockingRankedClient},
+	};
 
-	let doogile_id = UserIdentifier::Uuid(uuid!("3c8757790ab0400b8b9e3936e0dd535b"));
-	let params = GetMatchesParams::from(Pagination::count(100).unwrap());
-	let request = doogile_id.get_matches_blocking(&params);
-	println!("{request:?}");
-	assert!(request.is_ok());
+	let client = BlockingRankedClient::builder()
+		.transport(Cassette::new(
+			FIXTURES,
+			CassetteMode::Replay,
+			reqwest::blocking::Client::new(),
+		))
+		.no_retry()
+		.build()
+		.unwrap();
+	let user = client.get_user(&UserIdentifier::Nickname("nobody-recorded-this"), None);
+	assert!(matches!(user, Err(Error::Transport(_))));
 }
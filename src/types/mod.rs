@@ -3,6 +3,7 @@ use std::{
 	fmt::{self, Display},
 };
 
+use reqwest::StatusCode;
 use serde::{Deserialize, Deserializer, de};
 #[cfg(feature = "serialize")]
 use serde::{Serialize, ser::SerializeMap};
@@ -18,14 +19,13 @@ pub type PhasePoints = u16;
 pub type Rank = u32;
 pub type Season = u8;
 pub type Phase = u8;
-#[cfg(feature = "matches")]
 pub type MatchId = u64;
 #[cfg(feature = "weekly_races")]
 pub type WeeklyRaceId = u32;
 pub type MinecraftSeed = u64;
 
 #[cfg_attr(feature = "serialize", derive(Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
 #[serde(transparent)]
 pub struct Time(pub u64);
 
@@ -51,42 +51,240 @@ impl Time {
 /// Result with this crate's own `Error` type as default
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Kind of an error reported by the API
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiErrorKind {
+	/// The requested user, match or race does not exist
+	NotFound,
+	/// A path or query parameter was rejected
+	BadParameter,
+	/// Too many requests were made
+	RateLimited,
+	/// The request requires a (valid) API key
+	Unauthorized,
+	/// The API failed to handle the request
+	ServerError,
+	/// Any other error
+	Unknown,
+}
+impl Display for ApiErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			ApiErrorKind::NotFound => "not found",
+			ApiErrorKind::BadParameter => "bad parameter",
+			ApiErrorKind::RateLimited => "rate limited",
+			ApiErrorKind::Unauthorized => "unauthorized",
+			ApiErrorKind::ServerError => "server error",
+			ApiErrorKind::Unknown => "unknown error",
+		})
+	}
+}
+
+/// Error reported by the API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+	/// The kind of the error, parsed from the status and the message
+	pub kind: ApiErrorKind,
+	/// HTTP status of the response, if known
+	pub status: Option<StatusCode>,
+	/// The error message, if the API sent one
+	pub message: Option<Box<str>>,
+	/// The raw error payload (the `data` field of the response)
+	pub raw: Value,
+}
+impl ApiError {
+	/// Construct the error from the response `status` and the `raw` payload
+	pub fn new(status: Option<StatusCode>, raw: Value) -> Self {
+		let message = match &raw {
+			Value::String(message) => Some(message.as_str()),
+			Value::Object(fields) => ["message", "error"]
+				.into_iter()
+				.find_map(|key| fields.get(key).and_then(Value::as_str)),
+			_ => None,
+		};
+		Self {
+			kind: Self::classify(status, message),
+			status,
+			message: message.map(Into::into),
+			raw,
+		}
+	}
+
+	fn classify(status: Option<StatusCode>, message: Option<&str>) -> ApiErrorKind {
+		use ApiErrorKind as K;
+
+		match status {
+			Some(StatusCode::NOT_FOUND) => return K::NotFound,
+			Some(StatusCode::TOO_MANY_REQUESTS) => return K::RateLimited,
+			Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => return K::Unauthorized,
+			Some(status) if status.is_server_error() => return K::ServerError,
+			_ => {}
+		}
+		let message = message.unwrap_or_default().to_lowercase();
+		if message.contains("not exist") || message.contains("not found") {
+			K::NotFound
+		} else if message.contains("too many") || message.contains("rate limit") {
+			K::RateLimited
+		} else if message.contains("api key") || message.contains("unauthorized") {
+			K::Unauthorized
+		} else if message.contains("invalid")
+			|| message.contains("parameter")
+			|| status == Some(StatusCode::BAD_REQUEST)
+		{
+			K::BadParameter
+		} else {
+			K::Unknown
+		}
+	}
+
+	/// The error message, if the API sent one
+	pub fn message(&self) -> Option<&str> {
+		self.message.as_deref()
+	}
+}
+impl Display for ApiError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.message {
+			Some(message) => write!(f, "{}: {message}", self.kind),
+			None => write!(f, "{}: {}", self.kind, self.raw),
+		}
+	}
+}
+
+/// Failure to decode a response of the API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+	/// URL of the request
+	pub url: Box<str>,
+	/// HTTP status of the response
+	pub status: StatusCode,
+	/// Path to the field which failed to decode, such as `data.players[0].roleType`
+	pub path: Box<str>,
+	/// The deserializer's error message
+	pub message: Box<str>,
+	/// Truncated part of the response body around the failing field
+	pub body_excerpt: Box<str>,
+}
+impl Display for DecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"failed to decode `{}` of {} ({}): {} near `{}`",
+			self.path, self.url, self.status, self.message, self.body_excerpt
+		)
+	}
+}
+
 #[doc(hidden)]
 /// Error returned by a request to the API
 #[derive(Debug)]
 pub enum Error {
 	/// Ranked API error
-	Api(Value),
+	Api(ApiError),
+	/// The response could not be decoded
+	Decode(DecodeError),
 	/// Reqwest library error
 	Reqwest(reqwest::Error),
+	/// Error of a custom [`crate::client::Transport`]
+	Transport(Box<dyn std::error::Error + Send + Sync>),
+	/// Failure to read or write local data, such as sync checkpoints
+	Io(std::io::Error),
+	/// The API rejected the configured API key
+	InvalidApiKey,
+	/// The endpoint requires an API key, but the client has none configured
+	ApiKeyRequired,
+	/// The request failed after being retried
+	RetriesExhausted {
+		/// Amount of attempts made, including the first one
+		attempts: u32,
+		/// The error of the last attempt
+		last: Box<Error>,
+	},
 }
 
 impl PartialEq for Error {
 	fn eq(&self, other: &Self) -> bool {
 		match (self, other) {
 			(Error::Api(lhs), Error::Api(rhs)) => lhs == rhs,
+			(Error::Decode(lhs), Error::Decode(rhs)) => lhs == rhs,
 			(Error::Reqwest(lhs), Error::Reqwest(rhs)) => lhs.to_string() == rhs.to_string(),
+			(Error::Transport(lhs), Error::Transport(rhs)) => lhs.to_string() == rhs.to_string(),
+			(Error::Io(lhs), Error::Io(rhs)) => {
+				lhs.kind() == rhs.kind() && lhs.to_string() == rhs.to_string()
+			}
+			(Error::InvalidApiKey, Error::InvalidApiKey) => true,
+			(Error::ApiKeyRequired, Error::ApiKeyRequired) => true,
+			(
+				Error::RetriesExhausted {
+					attempts: lhs_attempts,
+					last: lhs_last,
+				},
+				Error::RetriesExhausted {
+					attempts: rhs_attempts,
+					last: rhs_last,
+				},
+			) => lhs_attempts == rhs_attempts && lhs_last == rhs_last,
 			_ => false,
 		}
 	}
 }
 impl Eq for Error {}
 
+impl Error {
+	/// The error reported by the API, also looking into retried requests
+	pub fn api_error(&self) -> Option<&ApiError> {
+		match self {
+			Error::Api(api_err) => Some(api_err),
+			Error::RetriesExhausted { last, .. } => last.api_error(),
+			_ => None,
+		}
+	}
+	/// Kind of the error reported by the API, see [`Error::api_error`]
+	pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+		self.api_error().map(|api_err| api_err.kind)
+	}
+}
+
 impl From<reqwest::Error> for Error {
 	fn from(value: reqwest::Error) -> Self {
 		Self::Reqwest(value)
 	}
 }
 
+impl From<std::io::Error> for Error {
+	fn from(value: std::io::Error) -> Self {
+		Self::Io(value)
+	}
+}
+
 impl Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Error::Api(api_err) => write!(f, "API Error: {api_err}"),
+			Error::Decode(decode_err) => write!(f, "Decode Error: {decode_err}"),
 			Error::Reqwest(req_err) => write!(f, "Reqwest Error: {req_err}"),
+			Error::Transport(transport_err) => write!(f, "Transport Error: {transport_err}"),
+			Error::Io(io_err) => write!(f, "IO Error: {io_err}"),
+			Error::InvalidApiKey => write!(f, "API Error: the API key was rejected"),
+			Error::ApiKeyRequired => write!(f, "API Error: the endpoint requires an API key"),
+			Error::RetriesExhausted { attempts, last } => {
+				write!(f, "{last} (after {attempts} attempts)")
+			}
+		}
+	}
+}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Reqwest(req_err) => Some(req_err),
+			Error::Transport(transport_err) => Some(transport_err.as_ref()),
+			Error::Io(io_err) => Some(io_err),
+			Error::RetriesExhausted { last, .. } => Some(last.as_ref()),
+			_ => None,
 		}
 	}
 }
-impl std::error::Error for Error {}
 
 #[derive(Debug, PartialEq, Eq, Deserialize)]
 #[serde(tag = "status", content = "data", rename_all = "camelCase")]
@@ -95,15 +293,22 @@ pub(crate) enum DeResult<T> {
 	Error(Value),
 }
 
-impl<T> From<DeResult<T>> for Result<T> {
-	fn from(value: DeResult<T>) -> Self {
-		match value {
+impl<T> DeResult<T> {
+	/// Convert into a [`Result`] given the HTTP `status` of the response
+	pub(crate) fn into_result(self, status: Option<StatusCode>) -> Result<T> {
+		match self {
 			DeResult::Success(t) => Ok(t),
-			DeResult::Error(error) => Err(Error::Api(error)),
+			DeResult::Error(error) => Err(Error::Api(ApiError::new(status, error))),
 		}
 	}
 }
 
+impl<T> From<DeResult<T>> for Result<T> {
+	fn from(value: DeResult<T>) -> Self {
+		value.into_result(None)
+	}
+}
+
 /// Container for ranked and casual values
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
@@ -129,6 +334,16 @@ impl<T> TwoUserData<T> {
 	pub fn user_2(&self) -> (Uuid, &T) {
 		(self.user_2_uuid, &self.user_2_data)
 	}
+	/// Data of the user with `uuid`, `None` if it is neither of the two users
+	pub fn get(&self, uuid: Uuid) -> Option<&T> {
+		if uuid == self.user_1_uuid {
+			Some(&self.user_1_data)
+		} else if uuid == self.user_2_uuid {
+			Some(&self.user_2_data)
+		} else {
+			None
+		}
+	}
 }
 
 impl<'de, T: Deserialize<'de>> Deserialize<'de> for TwoUserData<T> {
@@ -60,6 +60,78 @@ mod api_result {
 	}
 }
 
+mod api_error {
+	use reqwest::StatusCode;
+	use serde_json::{Value, json};
+
+	use crate::{
+		Error,
+		types::{ApiError, ApiErrorKind, DeResult},
+	};
+
+	#[test]
+	fn not_found() {
+		const JSON: &str = r#"{"status":"error","data":"User is not exists."}"#;
+		let result: DeResult<()> = serde_json::from_str(JSON).unwrap();
+		let error = result
+			.into_result(Some(StatusCode::BAD_REQUEST))
+			.unwrap_err();
+		let api_error = error.api_error().unwrap();
+		assert_eq!(api_error.kind, ApiErrorKind::NotFound);
+		assert_eq!(api_error.status, Some(StatusCode::BAD_REQUEST));
+		assert_eq!(api_error.message(), Some("User is not exists."));
+		assert_eq!(api_error.raw, json!("User is not exists."));
+	}
+
+	#[test]
+	fn kinds() {
+		let kind = |status, raw| ApiError::new(status, raw).kind;
+
+		assert_eq!(
+			kind(Some(StatusCode::TOO_MANY_REQUESTS), Value::Null),
+			ApiErrorKind::RateLimited
+		);
+		assert_eq!(
+			kind(Some(StatusCode::BAD_REQUEST), json!("Too many requests")),
+			ApiErrorKind::RateLimited
+		);
+		assert_eq!(
+			kind(Some(StatusCode::UNAUTHORIZED), json!("Invalid API key")),
+			ApiErrorKind::Unauthorized
+		);
+		assert_eq!(
+			kind(Some(StatusCode::BAD_REQUEST), json!("Invalid season")),
+			ApiErrorKind::BadParameter
+		);
+		assert_eq!(
+			kind(Some(StatusCode::BAD_REQUEST), Value::Null),
+			ApiErrorKind::BadParameter
+		);
+		assert_eq!(
+			kind(Some(StatusCode::BAD_GATEWAY), json!("Invalid response")),
+			ApiErrorKind::ServerError
+		);
+		assert_eq!(
+			kind(None, json!({ "message": "Match not found" })),
+			ApiErrorKind::NotFound
+		);
+		assert_eq!(kind(None, Value::Null), ApiErrorKind::Unknown);
+	}
+
+	#[test]
+	fn retried() {
+		let error = Error::RetriesExhausted {
+			attempts: 3,
+			last: Box::new(Error::Api(ApiError::new(
+				Some(StatusCode::TOO_MANY_REQUESTS),
+				Value::Null,
+			))),
+		};
+		assert_eq!(error.api_error_kind(), Some(ApiErrorKind::RateLimited));
+		assert_eq!(Error::InvalidApiKey.api_error_kind(), None);
+	}
+}
+
 mod two_user_data {
 	use serde::Deserialize;
 	use uuid::uuid;
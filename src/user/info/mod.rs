@@ -190,6 +190,7 @@ pub struct UserInfo {
 	#[cfg(feature = "weekly_races")]
 	pub weekly_races: Box<[WeeklyRaceResult]>,
 }
+#[cfg(feature = "weekly_races")]
 impl UserInfo {
 	/// User's weekly race stats
 	pub fn weekly_races(&self) -> &[WeeklyRaceResult] {
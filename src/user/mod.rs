@@ -1,11 +1,11 @@
 use std::fmt::Debug;
 
 use serde::Deserialize;
-use serde_repr::Deserialize_repr;
 use uuid::Uuid;
 #[cfg(feature = "serialize")]
-use {serde::Serialize, serde_repr::Serialize_repr};
+use serde::Serialize;
 
+use crate::helpers::repr_enum;
 use crate::types::{Elo, Rank};
 
 pub mod identifier;
@@ -14,15 +14,22 @@ pub mod requests;
 #[cfg(test)]
 mod tests;
 
-#[cfg_attr(feature = "serialize", derive(Serialize_repr))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize_repr)]
-#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum SupporterTier {
+	None,
+	Stone,
+	Iron,
+	Diamond,
+	/// A value not known to this crate, kept as sent by the API
+	Unknown(u8),
+}
+repr_enum!(SupporterTier {
 	None = 0,
 	Stone = 1,
 	Iron = 2,
 	Diamond = 3,
-}
+});
 
 /// A user's profile
 #[cfg_attr(feature = "serialize", derive(Serialize))]
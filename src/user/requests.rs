@@ -1,16 +1,20 @@
 use serde::Serialize;
 
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
-use crate::{Result, helpers::make_request, types::Season};
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	types::Season,
+};
 
 use super::{
 	identifier::UserIdentifier,
 	info::{UserInfo, all_seasons::AllSeasonUserInfo},
 };
 
-const BASE_URL: &str = "https://api.mcsrranked.com/users/{}";
-const ALL_SEASONS_URL: &str = "https://api.mcsrranked.com/users/{}/seasons";
+const BASE_PATH: &str = "users/{}";
+const ALL_SEASONS_PATH: &str = "users/{}/seasons";
 
 /// Parameters for [`UserIdentifier::get_user`]
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
@@ -29,14 +33,53 @@ impl GetUserParams {
 	}
 }
 
+impl RankedClient {
+	/// GET the user by `identifier` using given `params`
+	pub async fn get_user<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetUserParams>>,
+	) -> Result<UserInfo> {
+		self.request(BASE_PATH, [&identifier.to_string()], params.into())
+			.await
+	}
+	/// GET the user's info with data from all seasons by `identifier`
+	pub async fn get_user_all_seasons(
+		&self,
+		identifier: &UserIdentifier<'_>,
+	) -> Result<AllSeasonUserInfo> {
+		self.request(ALL_SEASONS_PATH, [&identifier.to_string()], None::<&()>)
+			.await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the user by `identifier` using given `params`
+	pub fn get_user<'a>(
+		&self,
+		identifier: &UserIdentifier<'_>,
+		params: impl Into<Option<&'a GetUserParams>>,
+	) -> Result<UserInfo> {
+		self.request(BASE_PATH, [&identifier.to_string()], params.into())
+	}
+	/// Synchronously GET the user's info with data from all seasons by `identifier`
+	pub fn get_user_all_seasons(
+		&self,
+		identifier: &UserIdentifier<'_>,
+	) -> Result<AllSeasonUserInfo> {
+		self.request(ALL_SEASONS_PATH, [&identifier.to_string()], None::<&()>)
+	}
+}
+
 impl<'a> UserIdentifier<'a> {
 	/// GET the user by identifier using given `params`
 	pub async fn get_user(&self, params: impl Into<Option<&'a GetUserParams>>) -> Result<UserInfo> {
-		make_request(BASE_URL, [&self.to_string()], params.into()).await
+		default_client().get_user(self, params).await
 	}
 	/// GET the user's info with data from all seasons
 	pub async fn get_user_all_seasons(&self) -> Result<AllSeasonUserInfo> {
-		make_request(ALL_SEASONS_URL, &[&self.to_string()], None::<&()>).await
+		default_client().get_user_all_seasons(self).await
 	}
 }
 
@@ -47,10 +90,10 @@ impl<'a> UserIdentifier<'a> {
 		&self,
 		params: impl Into<Option<&'a GetUserParams>>,
 	) -> Result<UserInfo> {
-		make_request_blocking(BASE_URL, [&self.to_string()], params.into())
+		default_blocking_client().get_user(self, params)
 	}
 	/// Synchronously GET the user's info with data from all seasons
 	pub fn get_user_all_seasons_blocking(&self) -> Result<AllSeasonUserInfo> {
-		make_request_blocking(ALL_SEASONS_URL, &[&self.to_string()], None::<&()>)
+		default_blocking_client().get_user_all_seasons(self)
 	}
 }
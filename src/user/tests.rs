@@ -67,6 +67,26 @@ fn user_profile() {
 	);
 }
 
+#[test]
+fn supporter_tier() {
+	assert_eq!(
+		serde_json::from_str::<SupporterTier>("2").unwrap(),
+		SupporterTier::Iron
+	);
+	assert!(SupporterTier::Diamond > SupporterTier::Stone);
+
+	#[cfg(feature = "strict")]
+	assert!(serde_json::from_str::<SupporterTier>("7").is_err());
+	#[cfg(not(feature = "strict"))]
+	{
+		let unknown = serde_json::from_str::<SupporterTier>("7").unwrap();
+		assert_eq!(unknown, SupporterTier::Unknown(7));
+		assert_eq!(unknown.value(), 7);
+		#[cfg(feature = "serialize")]
+		assert_eq!(serde_json::to_string(&unknown).unwrap(), "7");
+	}
+}
+
 #[test]
 fn all_seasons_info() {
 	const JSON: &str = r#"{"uuid":"9a8e24df4c8549d696a6951da84fa5c4","nickname":"Feinberg","roleType":3,"eloRate":2047,"eloRank":5,"country":"us","seasonResults":{"6":{"last":{"eloRate":2276,"eloRank":8,"phasePoint":125},"highest":2291,"lowest":2276,"phases":[{"phase":2,"eloRate":2089,"eloRank":6,"point":30},{"phase":3,"eloRate":2248,"eloRank":4,"point":50},{"phase":4,"eloRate":2276,"eloRank":8,"point":45}]},"7":{"last":{"eloRate":2047,"eloRank":5,"phasePoint":20},"highest":2110,"lowest":1539,"phases":[{"phase":1,"eloRate":1871,"eloRank":9,"point":20}]}}}"#;
@@ -1,20 +1,55 @@
 #[cfg(feature = "blocking")]
-use crate::helpers::make_request_blocking;
-use crate::{helpers::make_request, types::WeeklyRaceId, Result};
+use crate::client::blocking::{BlockingRankedClient, default_blocking_client};
+use crate::{
+	Result,
+	client::{RankedClient, default_client},
+	types::WeeklyRaceId,
+};
 
 use super::WeeklyRaceLeaderboardInfo;
 
-const BASE_URL: &str = "https://api.mcsrranked.com/weekly-race/{}";
+const BASE_PATH: &str = "weekly-race/{}";
+const CURRENT_PATH: &str = "weekly-race";
+
+impl RankedClient {
+	/// GET the weekly race leaderboard using given weekly `race_id`
+	pub async fn get_weekly_race(
+		&self,
+		race_id: WeeklyRaceId,
+	) -> Result<WeeklyRaceLeaderboardInfo> {
+		self.request(BASE_PATH, [&race_id.to_string()], None::<&()>)
+			.await
+	}
+
+	/// GET the current weekly race leaderboard
+	pub async fn get_current_weekly_race(&self) -> Result<WeeklyRaceLeaderboardInfo> {
+		self.request(CURRENT_PATH, &[] as &[&str], None::<&()>)
+			.await
+	}
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingRankedClient {
+	/// Synchronously GET the weekly race leaderboard using given weekly `race_id`
+	pub fn get_weekly_race(&self, race_id: WeeklyRaceId) -> Result<WeeklyRaceLeaderboardInfo> {
+		self.request(BASE_PATH, [&race_id.to_string()], None::<&()>)
+	}
+
+	/// Synchronously GET the current weekly race leaderboard
+	pub fn get_current_weekly_race(&self) -> Result<WeeklyRaceLeaderboardInfo> {
+		self.request(CURRENT_PATH, &[] as &[&str], None::<&()>)
+	}
+}
 
 impl WeeklyRaceLeaderboardInfo {
 	/// GET the weekly race leaderboard using given weekly `race_id`
 	pub async fn get_by_id(race_id: WeeklyRaceId) -> Result<Self> {
-		make_request(BASE_URL, [&race_id.to_string()], None::<&()>).await
+		default_client().get_weekly_race(race_id).await
 	}
 
 	/// GET the current weekly race leaderboard
 	pub async fn get_current() -> Result<Self> {
-		make_request(BASE_URL, &[] as &[&str], None::<&()>).await
+		default_client().get_current_weekly_race().await
 	}
 }
 
@@ -22,11 +57,11 @@ impl WeeklyRaceLeaderboardInfo {
 impl WeeklyRaceLeaderboardInfo {
 	/// Synchronously GET the weekly race leaderboard using given weekly `race_id`
 	pub fn get_by_id_blocking(race_id: WeeklyRaceId) -> Result<Self> {
-		make_request_blocking(BASE_URL, [&race_id.to_string()], None::<&()>)
+		default_blocking_client().get_weekly_race(race_id)
 	}
 
 	/// Synchronously GET the current weekly race leaderboard
 	pub fn get_current_blocking() -> Result<Self> {
-		make_request_blocking(BASE_URL, &[] as &[&str], None::<&()>)
+		default_blocking_client().get_current_weekly_race()
 	}
 }